@@ -0,0 +1,240 @@
+mod output;
+mod parse;
+
+pub use output::{format_result, input_hash, OutputFormat};
+pub use parse::{expect_prefix, expect_split_once, expect_token, parse_number, ParseError};
+
+use std::{
+    fmt,
+    path::PathBuf,
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use clap::{Args, Parser};
+use serde::{Serialize, Serializer};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{number}"),
+            Answer::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+/// Numbers stay numbers and texts become strings, e.g. in JSON output.
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Number(number) => serializer.serialize_i128(*number),
+            Answer::Text(text) => serializer.serialize_str(text),
+        }
+    }
+}
+
+macro_rules! impl_answer_from_number {
+    ($($number_type:ty),*) => {
+        $(
+            impl From<$number_type> for Answer {
+                fn from(number: $number_type) -> Self {
+                    Answer::Number(number as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from_number!(u8, u16, u32, u64, usize, i8, i16, i32, i64);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    NoAnswer,
+    Failed(String),
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::NoAnswer => write!(f, "none"),
+            SolveError::Failed(reason) => write!(f, "error ({reason})"),
+        }
+    }
+}
+
+pub type SolveResult = Result<Answer, SolveError>;
+
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+
+    /// Puzzle parameters given on the command line, their defaults fit the real input.
+    type Parameters: Args;
+
+    fn parse_input(input_text: &str) -> Result<Self::Input, ParseError>;
+
+    fn process_part1(input: &Self::Input, parameters: &Self::Parameters) -> SolveResult;
+
+    fn process_part2(input: &Self::Input, parameters: &Self::Parameters) -> SolveResult;
+
+    fn process(input: &Self::Input, parameters: &Self::Parameters, part: Part) -> SolveResult {
+        match part {
+            Part::One => Self::process_part1(input, parameters),
+            Part::Two => Self::process_part2(input, parameters),
+        }
+    }
+}
+
+/// Parameters of solutions without any.
+#[derive(Debug, Clone, Args)]
+pub struct NoParameters {}
+
+#[derive(Debug, Parser)]
+#[command(no_binary_name = true, name = "parameters")]
+struct ParametersArgs<P: Args> {
+    #[command(flatten)]
+    parameters: P,
+}
+
+/// Parses the parameters of `S` from `arguments`, exiting with usage on invalid ones.
+pub fn parse_parameters<S: Solution>(arguments: &[String]) -> S::Parameters {
+    ParametersArgs::<S::Parameters>::parse_from(arguments).parameters
+}
+
+pub fn default_parameters<S: Solution>() -> S::Parameters {
+    parse_parameters::<S>(&[])
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartResult {
+    pub part: Part,
+    pub result: SolveResult,
+    pub elapsed: Duration,
+}
+
+pub type PartResults = Vec<PartResult>;
+
+/// Type-erased entry point, so that solutions of different days can be stored side by side.
+/// The parameters are passed as command line arguments, see [`parse_parameters`].
+pub type Solver = fn(&str, &[Part], &[String]) -> Result<PartResults, ParseError>;
+
+pub fn solve<S: Solution>(
+    input_text: &str,
+    parts: &[Part],
+    parameters: &S::Parameters,
+) -> Result<PartResults, ParseError> {
+    let input = S::parse_input(input_text)?;
+
+    Ok(parts
+        .iter()
+        .map(|part| {
+            let start = Instant::now();
+            let result = S::process(&input, parameters, *part);
+            PartResult {
+                part: *part,
+                result,
+                elapsed: start.elapsed(),
+            }
+        })
+        .collect())
+}
+
+pub fn solve_with_arguments<S: Solution>(
+    input_text: &str,
+    parts: &[Part],
+    arguments: &[String],
+) -> Result<PartResults, ParseError> {
+    solve::<S>(input_text, parts, &parse_parameters::<S>(arguments))
+}
+
+pub fn print_results(day: u8, input_text: &str, results: &PartResults, format: OutputFormat) {
+    let input_hash = input_hash(input_text);
+    for part_result in results {
+        println!("{}", format_result(day, &input_hash, part_result, format));
+    }
+}
+
+pub fn run<S: Solution>(
+    input_text: &str,
+    parts: &[Part],
+    parameters: &S::Parameters,
+    format: OutputFormat,
+) -> Result<(), ParseError> {
+    let results = solve::<S>(input_text, parts, parameters)?;
+    print_results(S::DAY, input_text, &results, format);
+    Ok(())
+}
+
+#[derive(Debug, Parser)]
+struct MainArgs<P: Args> {
+    /// Input file, defaults to the real input
+    #[arg(long)]
+    input: Option<PathBuf>,
+
+    /// Output format of the results
+    #[arg(long, value_enum, default_value_t)]
+    format: OutputFormat,
+
+    #[command(flatten)]
+    parameters: P,
+}
+
+/// Reads the input file and solves both parts, reporting failures on stderr.
+pub fn run_main<S: Solution>(default_input_file_path: &str) -> ExitCode {
+    let args = MainArgs::<S::Parameters>::parse();
+    let input_file_path = args
+        .input
+        .unwrap_or_else(|| PathBuf::from(default_input_file_path));
+
+    let input_text = match std::fs::read_to_string(&input_file_path) {
+        Ok(input_text) => input_text,
+        Err(error) => {
+            eprintln!("cannot read {}: {error}", input_file_path.display());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match run::<S>(&input_text, &Part::ALL, &args.parameters, args.format) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{}", error.snippet(&input_text));
+            ExitCode::FAILURE
+        }
+    }
+}
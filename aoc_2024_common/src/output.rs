@@ -0,0 +1,112 @@
+use clap::ValueEnum;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::{Answer, PartResult};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// `result part1: ...` lines
+    #[default]
+    Text,
+    /// One JSON object per part and line
+    Json,
+}
+
+#[derive(Debug, Serialize)]
+struct JsonResult<'a> {
+    day: u8,
+    part: u8,
+    answer: Option<&'a Answer>,
+    error: Option<String>,
+    elapsed_ns: u128,
+    input_sha256: &'a str,
+}
+
+/// Hex encoded SHA-256 of the input, to tell which input an answer belongs to.
+pub fn input_hash(input_text: &str) -> String {
+    Sha256::digest(input_text.as_bytes())
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+pub fn format_result(
+    day: u8,
+    input_hash: &str,
+    part_result: &PartResult,
+    format: OutputFormat,
+) -> String {
+    let part = part_result.part.number();
+    match format {
+        OutputFormat::Text => match &part_result.result {
+            Ok(answer) => format!("result part{part}: {answer}"),
+            Err(error) => format!("result part{part}: {error}"),
+        },
+        OutputFormat::Json => {
+            let json_result = JsonResult {
+                day,
+                part,
+                answer: part_result.result.as_ref().ok(),
+                error: part_result.result.as_ref().err().map(ToString::to_string),
+                elapsed_ns: part_result.elapsed.as_nanos(),
+                input_sha256: input_hash,
+            };
+            serde_json::to_string(&json_result).expect("result is serializable")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::{Part, SolveError};
+
+    #[test]
+    fn test_format_result_json() {
+        let input_hash = input_hash("");
+        assert_eq!(
+            input_hash,
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+
+        let part_result = PartResult {
+            part: Part::Two,
+            result: Ok(Answer::from("58,44")),
+            elapsed: Duration::from_micros(2),
+        };
+        assert_eq!(
+            format_result(18, &input_hash, &part_result, OutputFormat::Json),
+            format!(
+                r#"{{"day":18,"part":2,"answer":"58,44","error":null,"elapsed_ns":2000,"input_sha256":"{input_hash}"}}"#
+            )
+        );
+
+        let part_result = PartResult {
+            part: Part::One,
+            result: Ok(Answer::from(292u32)),
+            elapsed: Duration::ZERO,
+        };
+        assert!(
+            format_result(18, &input_hash, &part_result, OutputFormat::Json)
+                .contains(r#""answer":292,"#)
+        );
+        assert_eq!(
+            format_result(18, &input_hash, &part_result, OutputFormat::Text),
+            "result part1: 292"
+        );
+    }
+
+    #[test]
+    fn test_format_result_error() {
+        let part_result = PartResult {
+            part: Part::Two,
+            result: Err(SolveError::NoAnswer),
+            elapsed: Duration::ZERO,
+        };
+        let json = format_result(5, "", &part_result, OutputFormat::Json);
+        assert!(json.contains(r#""answer":null,"error":"none""#));
+    }
+}
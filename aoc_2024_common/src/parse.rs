@@ -0,0 +1,132 @@
+use std::{error::Error, fmt, str::FromStr};
+
+/// Location of malformed input, `line` and `column` are 1-based.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, expected: impl Into<String>) -> Self {
+        Self {
+            line,
+            column,
+            expected: expected.into(),
+        }
+    }
+
+    /// Error located at the start of `token`, which has to be a slice of `input_text`.
+    pub fn at(input_text: &str, token: &str, expected: impl Into<String>) -> Self {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(input_text.as_ptr() as usize)
+            .filter(|offset| *offset <= input_text.len())
+            .expect("token is not part of the input text");
+
+        let preceding_text = &input_text[..offset];
+        let line = preceding_text.matches('\n').count() + 1;
+        let line_start = preceding_text.rfind('\n').map_or(0, |index| index + 1);
+        let column = preceding_text[line_start..].chars().count() + 1;
+
+        Self::new(line, column, expected)
+    }
+
+    /// Error located right after the end of `text`, for tokens that are missing.
+    pub fn after(input_text: &str, text: &str, expected: impl Into<String>) -> Self {
+        Self::at(input_text, &text[text.len()..], expected)
+    }
+
+    /// Renders the error together with the offending line and a caret below the column.
+    pub fn snippet(&self, input_text: &str) -> String {
+        let line_text = input_text.lines().nth(self.line - 1).unwrap_or_default();
+        let line_number = self.line.to_string();
+        let gutter = " ".repeat(line_number.len());
+        let caret_indent = " ".repeat(self.column - 1);
+
+        format!(
+            "error: {self}\n{gutter} |\n{line_number} | {line_text}\n{gutter} | {caret_indent}^"
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected {} at line {}, column {}",
+            self.expected, self.line, self.column
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `token`, which has to be a slice of `input_text`, as a number.
+pub fn parse_number<T: FromStr>(input_text: &str, token: &str) -> Result<T, ParseError> {
+    token
+        .parse::<T>()
+        .map_err(|_| ParseError::at(input_text, token, "number"))
+}
+
+/// Unwraps a token taken from `text`, reporting a missing token after the end of `text`.
+pub fn expect_token<'a>(
+    input_text: &str,
+    text: &str,
+    token: Option<&'a str>,
+    expected: &str,
+) -> Result<&'a str, ParseError> {
+    token.ok_or_else(|| ParseError::after(input_text, text, expected))
+}
+
+/// Strips `prefix` from `text`, which has to be a slice of `input_text`.
+pub fn expect_prefix<'a>(
+    input_text: &str,
+    text: &'a str,
+    prefix: &str,
+) -> Result<&'a str, ParseError> {
+    text.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::at(input_text, text, format!("`{}`", prefix.escape_debug())))
+}
+
+/// Splits `text` at the first `delimiter`, which has to be present.
+pub fn expect_split_once<'a>(
+    input_text: &str,
+    text: &'a str,
+    delimiter: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(delimiter).ok_or_else(|| {
+        ParseError::after(input_text, text, format!("`{}`", delimiter.escape_debug()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error_at() {
+        let input_text = "1 2\n3 x\n";
+        let token = input_text
+            .lines()
+            .nth(1)
+            .unwrap()
+            .split(' ')
+            .nth(1)
+            .unwrap();
+        let error = parse_number::<u32>(input_text, token).unwrap_err();
+        assert_eq!(error, ParseError::new(2, 3, "number"));
+        assert_eq!(
+            error.snippet(input_text),
+            "error: expected number at line 2, column 3\n  |\n2 | 3 x\n  |   ^"
+        );
+    }
+
+    #[test]
+    fn test_parse_error_after() {
+        let input_text = "1 2\n3\n";
+        let line = input_text.lines().nth(1).unwrap();
+        let error = expect_token(input_text, line, line.split(' ').nth(1), "number").unwrap_err();
+        assert_eq!(error, ParseError::new(2, 2, "number"));
+    }
+}
@@ -0,0 +1,90 @@
+use aoc_2024_common::{
+    expect_token, parse_number, NoParameters, ParseError, Solution, SolveResult,
+};
+
+fn parse_input(input_text: &str) -> Result<(Vec<u32>, Vec<u32>), ParseError> {
+    let mut list1: Vec<u32> = Vec::new();
+    let mut list2: Vec<u32> = Vec::new();
+
+    for line in input_text.lines() {
+        let mut numbers = line.split_whitespace();
+        let number1 = expect_token(input_text, line, numbers.next(), "number")?;
+        list1.push(parse_number(input_text, number1)?);
+        let number2 = expect_token(input_text, line, numbers.next(), "number")?;
+        list2.push(parse_number(input_text, number2)?);
+    }
+
+    Ok((list1, list2))
+}
+
+fn process_part1(mut list1: Vec<u32>, mut list2: Vec<u32>) -> u32 {
+    list1.sort();
+    list2.sort();
+
+    list1
+        .iter()
+        .zip(list2.iter())
+        .map(|(number1, number2)| number1.abs_diff(*number2))
+        .sum()
+}
+
+fn process_part2(list1: Vec<u32>, list2: Vec<u32>) -> u32 {
+    list1
+        .iter()
+        .map(|number1| number1 * list2.iter().filter(|number2| *number2 == number1).count() as u32)
+        .sum()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = (Vec<u32>, Vec<u32>);
+
+    type Parameters = NoParameters;
+
+    fn parse_input(input_text: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input_text)
+    }
+
+    fn process_part1(input: &Self::Input, _parameters: &Self::Parameters) -> SolveResult {
+        Ok(process_part1(input.0.clone(), input.1.clone()).into())
+    }
+
+    fn process_part2(input: &Self::Input, _parameters: &Self::Parameters) -> SolveResult {
+        Ok(process_part2(input.0.clone(), input.1.clone()).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process_part1() {
+        let input_file_path = "./test_input.txt";
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        let (list1, list2) = parse_input(&input_text).unwrap();
+        let result_part1 = process_part1(list1, list2);
+        assert_eq!(result_part1, 11);
+    }
+
+    #[test]
+    fn test_parse_input_error() {
+        let input_text = "3   4\n4   x\n2\n";
+        let error = parse_input(input_text).unwrap_err();
+        assert_eq!(error, ParseError::new(2, 5, "number"));
+        let error = parse_input("3   4\n2\n").unwrap_err();
+        assert_eq!(error, ParseError::new(2, 2, "number"));
+    }
+
+    #[test]
+    fn test_process_part2() {
+        let input_file_path = "./test_input.txt";
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        let (list1, list2) = parse_input(&input_text).unwrap();
+        let result_part2 = process_part2(list1, list2);
+        assert_eq!(result_part2, 31);
+    }
+}
@@ -0,0 +1,92 @@
+use aoc_2024_common::{parse_number, NoParameters, ParseError, Solution, SolveResult};
+
+fn parse_input(input_text: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+    input_text
+        .lines()
+        .map(|line| {
+            line.split_whitespace()
+                .map(|number| parse_number(input_text, number))
+                .collect()
+        })
+        .collect()
+}
+
+fn is_safe(report: &[u32]) -> bool {
+    let is_increasing = report[0] < report[1];
+    if is_increasing {
+        report
+            .windows(2)
+            .all(|window| (window[0] < window[1]) && ((window[1] - window[0]) <= 3))
+    } else {
+        report
+            .windows(2)
+            .all(|window| (window[1] < window[0]) && ((window[0] - window[1]) <= 3))
+    }
+}
+
+fn process_part1(data: &[Vec<u32>]) -> u32 {
+    data.iter().filter(|report| is_safe(report)).count() as u32
+}
+
+fn is_safe_with_tolerance(report: &[u32]) -> bool {
+    for remove_index in 0..report.len() {
+        let mut report_dampened: Vec<u32> = report.into();
+        report_dampened.remove(remove_index);
+        if is_safe(&report_dampened) {
+            return true;
+        }
+    }
+
+    false
+}
+
+fn process_part2(data: &[Vec<u32>]) -> u32 {
+    data.iter()
+        .filter(|report| is_safe_with_tolerance(report))
+        .count() as u32
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Vec<u32>>;
+
+    type Parameters = NoParameters;
+
+    fn parse_input(input_text: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input_text)
+    }
+
+    fn process_part1(input: &Self::Input, _parameters: &Self::Parameters) -> SolveResult {
+        Ok(process_part1(input).into())
+    }
+
+    fn process_part2(input: &Self::Input, _parameters: &Self::Parameters) -> SolveResult {
+        Ok(process_part2(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process_part1() {
+        let input_file_path = "./test_input.txt";
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        let input = parse_input(&input_text).unwrap();
+        let result_part1 = process_part1(&input);
+        assert_eq!(result_part1, 2);
+    }
+
+    #[test]
+    fn test_process_part2() {
+        let input_file_path = "./test_input.txt";
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        let input = parse_input(&input_text).unwrap();
+        let result_part2 = process_part2(&input);
+        assert_eq!(result_part2, 4);
+    }
+}
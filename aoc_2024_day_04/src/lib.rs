@@ -0,0 +1,114 @@
+use aoc_2024_common::{NoParameters, ParseError, Solution, SolveResult};
+use aoc_2024_grid::{Direction, Grid};
+
+fn parse_input(input_text: &str) -> Result<Grid<char>, ParseError> {
+    Grid::try_parse(input_text, "character", Some)
+}
+
+fn process_part1(input: &Grid<char>) -> usize {
+    let xmas = "XMAS";
+    let samx = "SAMX";
+    // rows, columns, diagonals \ and diagonals /
+    let directions = [
+        Direction::Right,
+        Direction::Down,
+        Direction::DownRight,
+        Direction::UpRight,
+    ];
+
+    let mut count: usize = 0;
+
+    for point in input.points() {
+        for direction in directions {
+            let word: String = input
+                .ray(point, direction)
+                .take(4)
+                .map(|word_point| input[word_point])
+                .collect();
+
+            if (word == xmas) || (word == samx) {
+                count += 1;
+            }
+        }
+    }
+
+    count
+}
+
+fn process_part2(input: &Grid<char>) -> usize {
+    let mut count: usize = 0;
+
+    for point in input.points() {
+        if input[point] != 'A' {
+            continue;
+        }
+
+        let corners = [
+            Direction::UpLeft,
+            Direction::UpRight,
+            Direction::DownLeft,
+            Direction::DownRight,
+        ]
+        .map(|direction| input.neighbor(point, direction).map(|corner| input[corner]));
+        let [Some(top_left), Some(top_right), Some(bottom_left), Some(bottom_right)] = corners
+        else {
+            continue;
+        };
+
+        let is_diagonal_1_mas = ((top_left == 'M') && (bottom_right == 'S'))
+            || ((top_left == 'S') && (bottom_right == 'M'));
+        let is_diagonal_2_mas = ((top_right == 'M') && (bottom_left == 'S'))
+            || ((top_right == 'S') && (bottom_left == 'M'));
+
+        if is_diagonal_1_mas && is_diagonal_2_mas {
+            count += 1;
+        }
+    }
+
+    count
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Grid<char>;
+
+    type Parameters = NoParameters;
+
+    fn parse_input(input_text: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input_text)
+    }
+
+    fn process_part1(input: &Self::Input, _parameters: &Self::Parameters) -> SolveResult {
+        Ok(process_part1(input).into())
+    }
+
+    fn process_part2(input: &Self::Input, _parameters: &Self::Parameters) -> SolveResult {
+        Ok(process_part2(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process_part1() {
+        let input_file_path = "./test_input.txt";
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        let input = parse_input(&input_text).unwrap();
+        let result_part1 = process_part1(&input);
+        assert_eq!(result_part1, 18);
+    }
+
+    #[test]
+    fn test_process_part2() {
+        let input_file_path = "./test_input.txt";
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        let input = parse_input(&input_text).unwrap();
+        let result_part2 = process_part2(&input);
+        assert_eq!(result_part2, 9);
+    }
+}
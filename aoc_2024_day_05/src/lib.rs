@@ -0,0 +1,132 @@
+use aoc_2024_common::{
+    expect_split_once, parse_number, NoParameters, ParseError, Solution, SolveResult,
+};
+use std::{cmp::Ordering, collections::HashSet};
+
+pub struct SafetyProtocol {
+    ordering_rules: HashSet<(u32, u32)>,
+    pages: Vec<Vec<u32>>,
+}
+
+fn parse_input(input_text: &str) -> Result<SafetyProtocol, ParseError> {
+    let (rules_text, pages_text) = expect_split_once(input_text, input_text, "\n\n")?;
+
+    let ordering_rules: HashSet<(u32, u32)> = rules_text
+        .lines()
+        .map(|line| {
+            let (number1, number2) = expect_split_once(input_text, line, "|")?;
+            Ok((
+                parse_number(input_text, number1)?,
+                parse_number(input_text, number2)?,
+            ))
+        })
+        .collect::<Result<_, ParseError>>()?;
+
+    let pages: Vec<Vec<u32>> = pages_text
+        .lines()
+        .map(|line| {
+            line.split(',')
+                .map(|number| parse_number(input_text, number))
+                .collect()
+        })
+        .collect::<Result<_, ParseError>>()?;
+
+    Ok(SafetyProtocol {
+        ordering_rules,
+        pages,
+    })
+}
+
+fn is_ordered(pages: &[u32], ordering_rules: &HashSet<(u32, u32)>) -> bool {
+    let mut ordered = true;
+    'outer_page_loop: for page_index_1 in 1..pages.len() {
+        for page_index_0 in 0..page_index_1 {
+            if ordering_rules.contains(&(pages[page_index_1], pages[page_index_0])) {
+                ordered = false;
+                break 'outer_page_loop;
+            }
+        }
+    }
+
+    ordered
+}
+
+fn process_part1(safety_protocol: &SafetyProtocol) -> u32 {
+    safety_protocol
+        .pages
+        .iter()
+        .filter(|pages| is_ordered(pages, &safety_protocol.ordering_rules))
+        .map(|pages| pages[pages.len() / 2])
+        .sum()
+}
+
+fn process_part2(safety_protocol: &SafetyProtocol) -> u32 {
+    let mut unordered_pages: Vec<Vec<u32>> = safety_protocol
+        .pages
+        .iter()
+        .filter(|pages| !is_ordered(pages, &safety_protocol.ordering_rules))
+        .cloned()
+        .collect();
+
+    unordered_pages.iter_mut().for_each(|pages| {
+        pages.sort_by(|page0, page1| {
+            if safety_protocol.ordering_rules.contains(&(*page0, *page1)) {
+                Ordering::Less
+            } else if safety_protocol.ordering_rules.contains(&(*page1, *page0)) {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+    });
+
+    unordered_pages
+        .iter()
+        .map(|pages| pages[pages.len() / 2])
+        .sum()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = SafetyProtocol;
+
+    type Parameters = NoParameters;
+
+    fn parse_input(input_text: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input_text)
+    }
+
+    fn process_part1(input: &Self::Input, _parameters: &Self::Parameters) -> SolveResult {
+        Ok(process_part1(input).into())
+    }
+
+    fn process_part2(input: &Self::Input, _parameters: &Self::Parameters) -> SolveResult {
+        Ok(process_part2(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process_part1() {
+        let input_file_path = "./test_input.txt";
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        let input = parse_input(&input_text).unwrap();
+        let result_part1 = process_part1(&input);
+        assert_eq!(result_part1, 143);
+    }
+
+    #[test]
+    fn test_process_part2() {
+        let input_file_path = "./test_input.txt";
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        let input = parse_input(&input_text).unwrap();
+        let result_part2 = process_part2(&input);
+        assert_eq!(result_part2, 123);
+    }
+}
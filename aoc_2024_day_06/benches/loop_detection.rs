@@ -0,0 +1,33 @@
+use std::hint::black_box;
+
+use aoc_2024_common::Solution;
+use aoc_2024_day_06::Day06;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+/// Finds every obstruction that makes the guard loop, once by simulating the whole patrol for
+/// each free cell and once on the jump table.
+fn bench_loop_detection(c: &mut Criterion) {
+    let input_text = std::fs::read_to_string("input.txt").unwrap();
+    let guarded_area = Day06::parse_input(&input_text).unwrap();
+
+    let mut group = c.benchmark_group("day_06_loop_detection");
+    group.sample_size(10);
+    group.bench_function("simulate_every_cell", |bencher| {
+        bencher.iter(|| {
+            guarded_area
+                .area()
+                .iter()
+                .filter(|(point, place)| {
+                    (**place == '.') && black_box(guarded_area.with_obstruction(*point)).has_loop()
+                })
+                .count()
+        })
+    });
+    group.bench_function("jump_table", |bencher| {
+        bencher.iter(|| black_box(guarded_area.loop_obstructions()).len())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_loop_detection);
+criterion_main!(benches);
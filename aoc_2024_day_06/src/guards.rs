@@ -0,0 +1,200 @@
+use std::collections::HashMap;
+
+use aoc_2024_grid::Point;
+
+use crate::GuardedArea;
+
+/// Guards, by index in [`GuardedArea::guards`], standing on the same cell after `step` moves,
+/// or two guards swapping cells head-on in that move, `position` being where the first one
+/// moved to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Collision {
+    pub step: usize,
+    pub position: Point,
+    pub guards: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MultiPatrolReport {
+    pub collisions: Vec<Collision>,
+    /// Cells visited by any guard, in row-major order.
+    pub covered_cells: Vec<Point>,
+    pub looping_guards: Vec<usize>,
+}
+
+fn format_guards(guards: &[usize]) -> String {
+    guards
+        .iter()
+        .map(|guard| guard.to_string())
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+impl MultiPatrolReport {
+    /// A table of the collisions followed by the looping guards.
+    pub fn to_text(&self) -> String {
+        let mut lines = vec!["step  row  column  guards".to_string()];
+        for collision in &self.collisions {
+            lines.push(format!(
+                "{:<5} {:<4} {:<7} {}",
+                collision.step,
+                collision.position.row,
+                collision.position.column,
+                format_guards(&collision.guards)
+            ));
+        }
+        if self.looping_guards.is_empty() {
+            lines.push("looping guards: none".to_string());
+        } else {
+            lines.push(format!(
+                "looping guards: {}",
+                format_guards(&self.looping_guards)
+            ));
+        }
+
+        lines.join("\n")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GuardState {
+    Patrolling,
+    Looping,
+    Left,
+}
+
+impl GuardedArea {
+    /// Moves all guards at the same time, one step each per tick. Guards do not block each
+    /// other, they only collide. Looping guards keep walking while there are guards still on
+    /// their first round, after that nothing new can happen.
+    pub fn patrol_all(&self) -> MultiPatrolReport {
+        let mut guarded_areas: Vec<GuardedArea> = self
+            .guards
+            .iter()
+            .map(|(position, direction)| self.with_guard(*position, *direction))
+            .collect();
+        let mut guard_states = vec![GuardState::Patrolling; guarded_areas.len()];
+        let mut report = MultiPatrolReport::default();
+
+        let mut step = 0;
+        while guard_states.contains(&GuardState::Patrolling) {
+            step += 1;
+            let previous_positions: Vec<Point> = guarded_areas
+                .iter()
+                .map(|guarded_area| guarded_area.guard_position)
+                .collect();
+            for (guarded_area, guard_state) in guarded_areas.iter_mut().zip(&mut guard_states) {
+                if *guard_state == GuardState::Left {
+                    continue;
+                }
+                if guarded_area.is_guard_leaving() {
+                    *guard_state = GuardState::Left;
+                } else if !guarded_area.move_guard() {
+                    *guard_state = GuardState::Looping;
+                }
+            }
+
+            let mut positions: Vec<(Point, usize)> = guarded_areas
+                .iter()
+                .zip(&guard_states)
+                .enumerate()
+                .filter(|(_, (_, guard_state))| **guard_state != GuardState::Left)
+                .map(|(guard, (guarded_area, _))| (guarded_area.guard_position, guard))
+                .collect();
+            positions.sort();
+            for same_position in positions.chunk_by(|(point_1, _), (point_2, _)| point_1 == point_2)
+            {
+                if same_position.len() > 1 {
+                    report.collisions.push(Collision {
+                        step,
+                        position: same_position[0].0,
+                        guards: same_position.iter().map(|(_, guard)| *guard).collect(),
+                    });
+                }
+            }
+
+            let mut guards_by_move: HashMap<(Point, Point), usize> = HashMap::new();
+            for (guard, (guarded_area, guard_state)) in
+                guarded_areas.iter().zip(&guard_states).enumerate()
+            {
+                let (position, previous_position) =
+                    (guarded_area.guard_position, previous_positions[guard]);
+                if *guard_state == GuardState::Left || position == previous_position {
+                    continue;
+                }
+                if let Some(other_guard) = guards_by_move.get(&(position, previous_position)) {
+                    report.collisions.push(Collision {
+                        step,
+                        position: previous_positions[guard],
+                        guards: vec![*other_guard, guard],
+                    });
+                }
+                guards_by_move.insert((previous_position, position), guard);
+            }
+        }
+
+        report.covered_cells = self
+            .area
+            .points()
+            .filter(|point| {
+                guarded_areas
+                    .iter()
+                    .any(|guarded_area| guarded_area.visits[*point] != 0)
+            })
+            .collect();
+        report.looping_guards = guard_states
+            .iter()
+            .enumerate()
+            .filter(|(_, guard_state)| **guard_state == GuardState::Looping)
+            .map(|(guard, _)| guard)
+            .collect();
+
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_input;
+
+    #[test]
+    fn test_patrol_all() {
+        let input_file_path = "./test_input_guards.txt";
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        let guarded_area = parse_input(&input_text).unwrap();
+        assert_eq!(guarded_area.guards().len(), 6);
+
+        let report = guarded_area.patrol_all();
+        assert_eq!(
+            report.collisions,
+            [
+                Collision {
+                    step: 1,
+                    position: Point::new(5, 4),
+                    guards: vec![4, 5]
+                },
+                Collision {
+                    step: 2,
+                    position: Point::new(1, 2),
+                    guards: vec![1, 2]
+                },
+                Collision {
+                    step: 2,
+                    position: Point::new(2, 2),
+                    guards: vec![0, 3]
+                }
+            ]
+        );
+        assert_eq!(report.covered_cells.len(), 14);
+        assert!(report.looping_guards.is_empty());
+        assert_eq!(
+            report.to_text(),
+            "step  row  column  guards\n\
+             1     5    4       4,5\n\
+             2     1    2       1,2\n\
+             2     2    2       0,3\n\
+             looping guards: none"
+        );
+    }
+}
@@ -0,0 +1,309 @@
+use std::path::PathBuf;
+
+use aoc_2024_common::{ParseError, Solution, SolveError, SolveResult};
+use aoc_2024_grid::{Direction, Grid, Point};
+use clap::Args;
+
+mod guards;
+mod patrol;
+mod render;
+
+pub use guards::{Collision, MultiPatrolReport};
+pub use patrol::PatrolMap;
+pub use render::PatrolTile;
+
+const OBSTRUCTION: char = '#';
+const FREE: char = '.';
+const UP: char = '^';
+const DOWN: char = 'v';
+const LEFT: char = '<';
+const RIGHT: char = '>';
+
+fn marker_direction(marker: char) -> Option<Direction> {
+    match marker {
+        UP => Some(Direction::Up),
+        DOWN => Some(Direction::Down),
+        LEFT => Some(Direction::Left),
+        RIGHT => Some(Direction::Right),
+        _ => None,
+    }
+}
+
+fn direction_marker(direction: Direction) -> char {
+    match direction {
+        Direction::Up => UP,
+        Direction::Down => DOWN,
+        Direction::Left => LEFT,
+        Direction::Right => RIGHT,
+        _ => unreachable!("the guard only walks straight"),
+    }
+}
+
+/// Bit of `direction` in the per-cell set of directions the guard faced there.
+fn direction_bit(direction: Direction) -> u8 {
+    match direction {
+        Direction::Up => 1,
+        Direction::Right => 2,
+        Direction::Down => 4,
+        Direction::Left => 8,
+        _ => unreachable!("the guard only walks straight"),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GuardedArea {
+    area: Grid<char>,
+    visits: Grid<u8>,
+    guard_position: Point,
+    guard_direction: Direction,
+    /// Start of every guard on the map, the one simulated above is the first.
+    guards: Vec<(Point, Direction)>,
+}
+
+impl GuardedArea {
+    pub fn area(&self) -> &Grid<char> {
+        &self.area
+    }
+
+    pub fn guards(&self) -> &[(Point, Direction)] {
+        &self.guards
+    }
+
+    /// Copy of the area with a single guard at `position`, who has not moved yet.
+    pub fn with_guard(&self, position: Point, direction: Direction) -> Self {
+        let mut guarded_area = Self {
+            area: self.area.clone(),
+            visits: Grid::new(self.area.num_rows(), self.area.num_columns(), 0),
+            guard_position: position,
+            guard_direction: direction,
+            guards: vec![(position, direction)],
+        };
+        guarded_area.mark_guard_visit();
+        guarded_area
+    }
+
+    /// Copy of the area with one more obstruction at `point`.
+    pub fn with_obstruction(&self, point: Point) -> Self {
+        let mut guarded_area = self.clone();
+        guarded_area.area[point] = OBSTRUCTION;
+        guarded_area
+    }
+
+    fn guard_next_position(&self) -> Option<Point> {
+        self.area
+            .neighbor(self.guard_position, self.guard_direction)
+    }
+
+    pub fn is_guard_leaving(&self) -> bool {
+        self.guard_next_position().is_none()
+    }
+
+    fn turn_guard_right(&mut self) {
+        self.guard_direction = self.guard_direction.turn_right();
+    }
+
+    fn is_guard_path_blocked(&self) -> bool {
+        self.guard_next_position()
+            .is_some_and(|next_position| self.area[next_position] == OBSTRUCTION)
+    }
+
+    /// Records the guard facing its direction on its cell, returning `false` if it has been
+    /// there facing the same way before.
+    fn mark_guard_visit(&mut self) -> bool {
+        let direction_bit = direction_bit(self.guard_direction);
+        let visits = &mut self.visits[self.guard_position];
+        let is_new_visit = (*visits & direction_bit) == 0;
+        *visits |= direction_bit;
+        is_new_visit
+    }
+
+    /// Turns the guard until the path is free and moves it one step. Returns `false` as soon
+    /// as the guard is back in a position and direction it had before, so it walks in a loop.
+    pub fn move_guard(&mut self) -> bool {
+        while self.is_guard_path_blocked() {
+            self.turn_guard_right();
+            if !self.mark_guard_visit() {
+                return false;
+            }
+        }
+
+        let Some(next_position) = self.guard_next_position() else {
+            return true;
+        };
+
+        self.guard_position = next_position;
+        self.mark_guard_visit()
+    }
+
+    pub fn has_loop(&self) -> bool {
+        let mut guarded_area = self.clone();
+
+        while !guarded_area.is_guard_leaving() {
+            if !guarded_area.move_guard() {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Cells where one more obstruction makes the guard walk in a loop.
+    pub fn loop_obstructions(&self) -> Vec<Point> {
+        PatrolMap::new(&self.area, OBSTRUCTION)
+            .loop_obstructions(self.guard_position, self.guard_direction)
+    }
+}
+
+fn parse_input(input_text: &str) -> Result<GuardedArea, ParseError> {
+    let area = Grid::try_parse(input_text, "one of `.#^v<>`", |ch| {
+        (matches!(ch, FREE | OBSTRUCTION) || marker_direction(ch).is_some()).then_some(ch)
+    })?;
+    let guards: Vec<(Point, Direction)> = area
+        .iter()
+        .filter_map(|(point, place)| Some((point, marker_direction(*place)?)))
+        .collect();
+    let Some(&(guard_position, guard_direction)) = guards.first() else {
+        return Err(ParseError::after(
+            input_text,
+            input_text,
+            "a guard, one of `^v<>`",
+        ));
+    };
+
+    let visits = Grid::new(area.num_rows(), area.num_columns(), 0);
+
+    let mut guarded_area = GuardedArea {
+        area,
+        visits,
+        guard_position,
+        guard_direction,
+        guards,
+    };
+    guarded_area.mark_guard_visit();
+
+    Ok(guarded_area)
+}
+
+fn process_part1(mut guarded_area: GuardedArea) -> usize {
+    while !guarded_area.is_guard_leaving() && guarded_area.move_guard() {}
+
+    guarded_area
+        .visits
+        .iter()
+        .filter(|(_, visits)| **visits != 0)
+        .count()
+}
+
+fn process_part2(guarded_area: GuardedArea) -> usize {
+    guarded_area.loop_obstructions().len()
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct Day06Parameters {
+    /// Let every guard on the map patrol at once and count the cells any of them covers in part 1,
+    /// printing their collisions and the looping guards to stderr
+    #[arg(long)]
+    all_guards: bool,
+    /// Write the patrol route and loop obstructions as a PPM image to this path in part 1
+    #[arg(long)]
+    render_ppm: Option<PathBuf>,
+    /// Pixels per cell side of the PPM image
+    #[arg(long, default_value_t = 8)]
+    ppm_scale: usize,
+    /// Print the patrol route and loop obstructions with terminal colors to stderr in part 1
+    #[arg(long)]
+    render_ansi: bool,
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = GuardedArea;
+
+    type Parameters = Day06Parameters;
+
+    fn parse_input(input_text: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input_text)
+    }
+
+    fn process_part1(input: &Self::Input, parameters: &Self::Parameters) -> SolveResult {
+        if let Some(image_file_path) = &parameters.render_ppm {
+            std::fs::write(image_file_path, input.render_ppm(parameters.ppm_scale)).map_err(
+                |error| {
+                    SolveError::Failed(format!(
+                        "cannot write {}: {error}",
+                        image_file_path.display()
+                    ))
+                },
+            )?;
+        }
+        if parameters.render_ansi {
+            eprint!("{}", input.render_ansi());
+        }
+
+        if parameters.all_guards {
+            let report = input.patrol_all();
+            eprintln!("{}", report.to_text());
+            return Ok(report.covered_cells.len().into());
+        }
+
+        Ok(process_part1(input.clone()).into())
+    }
+
+    fn process_part2(input: &Self::Input, _parameters: &Self::Parameters) -> SolveResult {
+        Ok(process_part2(input.clone()).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process_part1() {
+        let input_file_path = "./test_input.txt";
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        let input = parse_input(&input_text).unwrap();
+        let result_part1 = process_part1(input);
+        assert_eq!(result_part1, 41);
+    }
+
+    #[test]
+    fn test_process_part2() {
+        let input_file_path = "./test_input.txt";
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        let input = parse_input(&input_text).unwrap();
+        let result_part2 = process_part2(input);
+        assert_eq!(result_part2, 6);
+    }
+
+    #[test]
+    fn test_parse_input_guard() {
+        let input = parse_input("...\n.#<\n...").unwrap();
+        assert_eq!(input.guards(), [(Point::new(1, 2), Direction::Left)]);
+        assert_eq!(process_part1(input), 2);
+
+        let error = parse_input("...\n.#.").unwrap_err();
+        assert_eq!(error, ParseError::new(2, 4, "a guard, one of `^v<>`"));
+    }
+
+    #[test]
+    fn test_crossing_paths() {
+        let input_file_path = "./test_input_crossing.txt";
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        let input = parse_input(&input_text).unwrap();
+        assert!(!input.has_loop());
+        assert_eq!(process_part1(input), 19);
+
+        let input_file_path = "./test_input_crossing_loop.txt";
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        let mut input = parse_input(&input_text).unwrap();
+        assert!(input.has_loop());
+        let num_moves = std::iter::from_fn(|| Some(input.move_guard()))
+            .take_while(|is_new_visit| *is_new_visit)
+            .count();
+        assert_eq!(num_moves, 5);
+    }
+}
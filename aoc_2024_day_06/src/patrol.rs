@@ -0,0 +1,222 @@
+use aoc_2024_grid::{Direction, Grid, Point};
+
+const LEAVES: u32 = u32::MAX;
+
+fn cardinal_index(direction: Direction) -> usize {
+    Direction::CARDINAL
+        .iter()
+        .position(|cardinal_direction| *cardinal_direction == direction)
+        .expect("the guard only walks straight")
+}
+
+/// Set of `(cell, direction)` states with one bit each.
+#[derive(Debug, Clone)]
+struct StateSet {
+    words: Vec<u64>,
+}
+
+impl StateSet {
+    fn new(num_states: usize) -> Self {
+        Self {
+            words: vec![0; num_states.div_ceil(64)],
+        }
+    }
+
+    /// Returns `false` if `state` was in the set already.
+    fn insert(&mut self, state: usize) -> bool {
+        let word = &mut self.words[state / 64];
+        let bit = 1 << (state % 64);
+        let is_new = (*word & bit) == 0;
+        *word |= bit;
+        is_new
+    }
+
+    fn clear(&mut self) {
+        self.words.fill(0);
+    }
+}
+
+/// The area reduced to what a patrol needs: where the obstructions are and, for every cell and
+/// direction, the cell in front of the next obstruction the guard runs into.
+#[derive(Debug, Clone)]
+pub struct PatrolMap {
+    num_rows: usize,
+    num_columns: usize,
+    is_obstruction: Vec<bool>,
+    stops: Vec<[u32; 4]>,
+}
+
+impl PatrolMap {
+    pub fn new(area: &Grid<char>, obstruction: char) -> Self {
+        let num_cells = area.num_rows() * area.num_columns();
+        let mut patrol_map = Self {
+            num_rows: area.num_rows(),
+            num_columns: area.num_columns(),
+            is_obstruction: area
+                .iter()
+                .map(|(_, place)| *place == obstruction)
+                .collect(),
+            stops: vec![[LEAVES; 4]; num_cells],
+        };
+
+        // the cell ahead has to be done first, it comes earlier in row-major order when walking
+        // up or left and later when walking right or down
+        for (direction_index, direction) in Direction::CARDINAL.into_iter().enumerate() {
+            let mut points: Vec<Point> = area.points().collect();
+            if matches!(direction, Direction::Right | Direction::Down) {
+                points.reverse();
+            }
+
+            for point in points {
+                let stop = match patrol_map.ahead(point, direction) {
+                    None => LEAVES,
+                    Some(ahead) if patrol_map.is_obstruction[patrol_map.index(ahead)] => {
+                        patrol_map.index(point) as u32
+                    }
+                    Some(ahead) => patrol_map.stops[patrol_map.index(ahead)][direction_index],
+                };
+                let index = patrol_map.index(point);
+                patrol_map.stops[index][direction_index] = stop;
+            }
+        }
+
+        patrol_map
+    }
+
+    fn index(&self, point: Point) -> usize {
+        point.row * self.num_columns + point.column
+    }
+
+    fn point(&self, index: usize) -> Point {
+        Point::new(index / self.num_columns, index % self.num_columns)
+    }
+
+    fn ahead(&self, position: Point, direction: Direction) -> Option<Point> {
+        position
+            .step(direction)
+            .filter(|ahead| (ahead.row < self.num_rows) && (ahead.column < self.num_columns))
+    }
+
+    /// Where the guard stops walking straight from `position`, taking `extra_obstruction` into
+    /// account, or `None` if the guard leaves the area.
+    fn stop(
+        &self,
+        position: Point,
+        direction_index: usize,
+        extra_obstruction: Point,
+    ) -> Option<Point> {
+        let stop = self.stops[self.index(position)][direction_index];
+        let stop = (stop != LEAVES).then(|| self.point(stop as usize));
+
+        let is_same_row = extra_obstruction.row == position.row;
+        let is_same_column = extra_obstruction.column == position.column;
+        let steps_to_extra_obstruction = match Direction::CARDINAL[direction_index] {
+            Direction::Up => (is_same_column && (extra_obstruction.row < position.row))
+                .then(|| position.row - extra_obstruction.row),
+            Direction::Right => (is_same_row && (extra_obstruction.column > position.column))
+                .then(|| extra_obstruction.column - position.column),
+            Direction::Down => (is_same_column && (extra_obstruction.row > position.row))
+                .then(|| extra_obstruction.row - position.row),
+            _ => (is_same_row && (extra_obstruction.column < position.column))
+                .then(|| position.column - extra_obstruction.column),
+        };
+
+        match steps_to_extra_obstruction {
+            Some(steps_to_extra_obstruction)
+                if stop.is_none_or(|stop| {
+                    steps_to_extra_obstruction <= stop.manhattan_distance(&position)
+                }) =>
+            {
+                let (row_delta, column_delta) = Direction::CARDINAL[direction_index].delta();
+                let num_steps = steps_to_extra_obstruction as isize - 1;
+                position.offset(row_delta * num_steps, column_delta * num_steps)
+            }
+            _ => stop,
+        }
+    }
+
+    /// Jumps from stop to stop and reports a loop as soon as the guard turns at the same cell
+    /// in the same direction twice.
+    fn has_loop_with(
+        &self,
+        extra_obstruction: Point,
+        position: Point,
+        direction_index: usize,
+        visited: &mut StateSet,
+    ) -> bool {
+        visited.clear();
+        let mut position = position;
+        let mut direction_index = direction_index;
+
+        while let Some(stop) = self.stop(position, direction_index, extra_obstruction) {
+            position = stop;
+            direction_index = (direction_index + 1) % 4;
+            if !visited.insert(self.index(position) * 4 + direction_index) {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Cells where one more obstruction makes the guard loop, in the order the guard reaches
+    /// them. Only cells on the patrol route can change it, and as the route up to the first
+    /// visit of a cell stays the same, the loop check starts right in front of that cell.
+    pub fn loop_obstructions(&self, start: Point, start_direction: Direction) -> Vec<Point> {
+        let num_cells = self.num_rows * self.num_columns;
+        let mut is_tried = vec![false; num_cells];
+        is_tried[self.index(start)] = true;
+        let mut visited = StateSet::new(num_cells * 4);
+        let mut loop_obstructions = Vec::new();
+
+        let mut position = start;
+        let mut direction_index = cardinal_index(start_direction);
+        // the patrol leaves the area, so every state comes up once at most
+        for _ in 0..(num_cells * 4) {
+            let Some(ahead) = self.ahead(position, Direction::CARDINAL[direction_index]) else {
+                break;
+            };
+            let ahead_index = self.index(ahead);
+            if self.is_obstruction[ahead_index] {
+                direction_index = (direction_index + 1) % 4;
+                continue;
+            }
+
+            if !is_tried[ahead_index] {
+                is_tried[ahead_index] = true;
+                if self.has_loop_with(ahead, position, direction_index, &mut visited) {
+                    loop_obstructions.push(ahead);
+                }
+            }
+            position = ahead;
+        }
+
+        loop_obstructions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_input, FREE, OBSTRUCTION};
+
+    #[test]
+    fn test_loop_obstructions() {
+        let input_file_path = "./test_input.txt";
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        let guarded_area = parse_input(&input_text).unwrap();
+        let patrol_map = PatrolMap::new(guarded_area.area(), OBSTRUCTION);
+
+        let mut loop_obstructions = patrol_map.loop_obstructions(Point::new(6, 4), Direction::Up);
+        loop_obstructions.sort();
+        let expected_loop_obstructions: Vec<Point> = guarded_area
+            .area()
+            .points()
+            .filter(|point| {
+                (guarded_area.area()[*point] == FREE)
+                    && guarded_area.with_obstruction(*point).has_loop()
+            })
+            .collect();
+        assert_eq!(loop_obstructions, expected_loop_obstructions);
+    }
+}
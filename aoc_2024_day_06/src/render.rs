@@ -0,0 +1,137 @@
+use std::fmt;
+
+use aoc_2024_grid::{Direction, Grid};
+
+use crate::{direction_bit, direction_marker, GuardedArea, OBSTRUCTION};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PatrolTile {
+    Free,
+    Obstruction,
+    Start(Direction),
+    /// Part of the route, with the directions the guard faced there as in `GuardedArea::visits`.
+    Route(u8),
+    LoopObstruction,
+}
+
+impl PatrolTile {
+    fn rgb(&self) -> [u8; 3] {
+        match self {
+            PatrolTile::Free => [24, 24, 32],
+            PatrolTile::Obstruction => [160, 160, 160],
+            PatrolTile::Start(_) => [40, 200, 60],
+            PatrolTile::Route(_) => [60, 110, 230],
+            PatrolTile::LoopObstruction => [230, 50, 40],
+        }
+    }
+
+    fn ansi_color(&self) -> u8 {
+        match self {
+            PatrolTile::Free => 90,
+            PatrolTile::Obstruction => 37,
+            PatrolTile::Start(_) => 32,
+            PatrolTile::Route(_) => 34,
+            PatrolTile::LoopObstruction => 31,
+        }
+    }
+}
+
+impl fmt::Display for PatrolTile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let vertical = direction_bit(Direction::Up) | direction_bit(Direction::Down);
+        let horizontal = direction_bit(Direction::Left) | direction_bit(Direction::Right);
+        let symbol = match self {
+            PatrolTile::Free => '.',
+            PatrolTile::Obstruction => OBSTRUCTION,
+            PatrolTile::Start(direction) => direction_marker(*direction),
+            PatrolTile::Route(visits) if (visits & vertical) == 0 => '-',
+            PatrolTile::Route(visits) if (visits & horizontal) == 0 => '|',
+            PatrolTile::Route(_) => '+',
+            PatrolTile::LoopObstruction => 'O',
+        };
+        write!(f, "{symbol}")
+    }
+}
+
+impl GuardedArea {
+    /// The area after the patrol, with the route and every obstruction that makes the guard
+    /// loop. Printing the grid gives the plain text version.
+    pub fn patrol_tiles(&self) -> Grid<PatrolTile> {
+        let mut guarded_area = self.clone();
+        while !guarded_area.is_guard_leaving() && guarded_area.move_guard() {}
+
+        let mut tiles = self.area.map(|place| match *place {
+            OBSTRUCTION => PatrolTile::Obstruction,
+            _ => PatrolTile::Free,
+        });
+        for (point, visits) in guarded_area.visits.iter() {
+            if *visits != 0 {
+                tiles[point] = PatrolTile::Route(*visits);
+            }
+        }
+        for point in self.loop_obstructions() {
+            tiles[point] = PatrolTile::LoopObstruction;
+        }
+        tiles[self.guard_position] = PatrolTile::Start(self.guard_direction);
+
+        tiles
+    }
+
+    /// Colored text for terminals, one line per row.
+    pub fn render_ansi(&self) -> String {
+        let tiles = self.patrol_tiles();
+        let mut text = String::new();
+        for row in tiles.rows() {
+            for tile in row {
+                text.push_str(&format!("\x1b[{}m{tile}", tile.ansi_color()));
+            }
+            text.push_str("\x1b[0m\n");
+        }
+
+        text
+    }
+
+    /// Binary PPM image with `scale` by `scale` pixels per cell.
+    pub fn render_ppm(&self, scale: usize) -> Vec<u8> {
+        let tiles = self.patrol_tiles();
+        let width = tiles.num_columns() * scale;
+        let height = tiles.num_rows() * scale;
+        let mut image = format!("P6\n{width} {height}\n255\n").into_bytes();
+        for row in tiles.rows() {
+            let pixel_row: Vec<u8> = row
+                .iter()
+                .flat_map(|tile| std::iter::repeat_n(tile.rgb(), scale))
+                .flatten()
+                .collect();
+            for _ in 0..scale {
+                image.extend(&pixel_row);
+            }
+        }
+
+        image
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse_input;
+
+    #[test]
+    fn test_render() {
+        let input_file_path = "./test_input.txt";
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        let guarded_area = parse_input(&input_text).unwrap();
+
+        let text = guarded_area.patrol_tiles().to_string();
+        assert_eq!(text.matches('O').count(), 6);
+        assert_eq!(text.lines().nth(6), Some(".#+O^-+-+."));
+
+        let ansi_text = guarded_area.render_ansi();
+        assert!(ansi_text.starts_with("\x1b[90m.\x1b[90m.\x1b[90m.\x1b[90m.\x1b[37m#"));
+
+        let image = guarded_area.render_ppm(2);
+        let header = b"P6\n20 20\n255\n";
+        assert!(image.starts_with(header));
+        assert_eq!(image.len(), header.len() + 20 * 20 * 3);
+    }
+}
@@ -0,0 +1,135 @@
+use aoc_2024_common::{NoParameters, ParseError, Solution, SolveResult};
+use aoc_2024_grid::{Grid, Point};
+use std::collections::{HashMap, HashSet};
+
+fn parse_input(input_text: &str) -> Result<Grid<char>, ParseError> {
+    Grid::try_parse(input_text, "character", Some)
+}
+
+fn get_antenna_locations(antenna_map: &Grid<char>) -> HashMap<char, Vec<Point>> {
+    let mut antenna_locations: HashMap<char, Vec<Point>> = HashMap::new();
+    for (point, frequency) in antenna_map.iter() {
+        if frequency.is_alphanumeric() {
+            antenna_locations
+                .entry(*frequency)
+                .or_insert(Vec::with_capacity(4))
+                .push(point);
+        }
+    }
+
+    antenna_locations
+}
+
+fn location_delta(location_1: Point, location_2: Point) -> (isize, isize) {
+    (
+        location_2.row as isize - location_1.row as isize,
+        location_2.column as isize - location_1.column as isize,
+    )
+}
+
+fn process_part1(antenna_map: &Grid<char>) -> usize {
+    let antenna_locations = get_antenna_locations(antenna_map);
+
+    let mut antinode_locations: HashSet<Point> = HashSet::new();
+    for locations in antenna_locations.values() {
+        for location_index_1 in 0..(locations.len() - 1) {
+            for location_index_2 in (location_index_1 + 1)..locations.len() {
+                let (row_delta, column_delta) =
+                    location_delta(locations[location_index_1], locations[location_index_2]);
+
+                let antinode_location_1 =
+                    locations[location_index_1].offset(-row_delta, -column_delta);
+                let antinode_location_2 =
+                    locations[location_index_2].offset(row_delta, column_delta);
+
+                for antinode_location in [antinode_location_1, antinode_location_2]
+                    .into_iter()
+                    .flatten()
+                {
+                    if antenna_map.contains(antinode_location) {
+                        antinode_locations.insert(antinode_location);
+                    }
+                }
+            }
+        }
+    }
+
+    antinode_locations.len()
+}
+
+fn process_part2(antenna_map: &Grid<char>) -> usize {
+    let antenna_locations = get_antenna_locations(antenna_map);
+
+    let mut antinode_locations: HashSet<Point> = HashSet::new();
+    for locations in antenna_locations.values() {
+        for location_index_1 in 0..(locations.len() - 1) {
+            for location_index_2 in (location_index_1 + 1)..locations.len() {
+                let (row_delta, column_delta) =
+                    location_delta(locations[location_index_1], locations[location_index_2]);
+
+                let mut antinode_location_1 = Some(locations[location_index_1]);
+                while let Some(location) =
+                    antinode_location_1.filter(|location| antenna_map.contains(*location))
+                {
+                    antinode_locations.insert(location);
+                    antinode_location_1 = location.offset(-row_delta, -column_delta);
+                }
+
+                let mut antinode_location_2 = Some(locations[location_index_2]);
+                while let Some(location) =
+                    antinode_location_2.filter(|location| antenna_map.contains(*location))
+                {
+                    antinode_locations.insert(location);
+                    antinode_location_2 = location.offset(row_delta, column_delta);
+                }
+            }
+        }
+    }
+
+    antinode_locations.len()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Grid<char>;
+
+    type Parameters = NoParameters;
+
+    fn parse_input(input_text: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input_text)
+    }
+
+    fn process_part1(input: &Self::Input, _parameters: &Self::Parameters) -> SolveResult {
+        Ok(process_part1(input).into())
+    }
+
+    fn process_part2(input: &Self::Input, _parameters: &Self::Parameters) -> SolveResult {
+        Ok(process_part2(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process_part1() {
+        let input_file_path = "test_input.txt";
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        let input = parse_input(&input_text).unwrap();
+        let result_part1 = process_part1(&input);
+        assert_eq!(result_part1, 14);
+    }
+
+    #[test]
+    fn test_process_part2() {
+        let input_file_path = "test_input.txt";
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        let input = parse_input(&input_text).unwrap();
+        let result_part2 = process_part2(&input);
+        assert_eq!(result_part2, 34);
+    }
+}
@@ -0,0 +1,321 @@
+use std::cmp::Reverse;
+
+use crate::{DiskMap, Extent, FileExtent, FreeSpaceIndex};
+
+/// Relocation of `length` consecutive blocks of a file from `from` to `to`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileMove {
+    pub file_id: u64,
+    pub from: usize,
+    pub to: usize,
+    pub length: usize,
+}
+
+/// File extents after a compaction, together with the moves that got there in order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Compaction {
+    pub files: Vec<FileExtent>,
+    pub moves: Vec<FileMove>,
+}
+
+impl Compaction {
+    fn new(files: Vec<FileExtent>) -> Self {
+        Self {
+            files,
+            moves: Vec::new(),
+        }
+    }
+
+    fn record_move(&mut self, file_id: u64, from: usize, to: usize, length: usize) {
+        self.moves.push(FileMove {
+            file_id,
+            from,
+            to,
+            length,
+        });
+    }
+
+    pub fn num_moves(&self) -> usize {
+        self.moves.len()
+    }
+
+    pub fn num_moved_blocks(&self) -> usize {
+        self.moves.iter().map(|file_move| file_move.length).sum()
+    }
+
+    pub fn checksum(&self) -> u64 {
+        self.files.iter().map(FileExtent::checksum).sum()
+    }
+
+    /// Number of free gaps between the first and the last used block.
+    pub fn fragmentation(&self) -> usize {
+        let mut used_extents: Vec<(usize, usize)> = self
+            .files
+            .iter()
+            .filter(|file| file.length > 0)
+            .map(|file| (file.start, file.end()))
+            .collect();
+        used_extents.sort();
+
+        used_extents
+            .windows(2)
+            .filter(|pair| pair[1].0 > pair[0].1)
+            .count()
+    }
+}
+
+pub trait CompactionStrategy {
+    fn name(&self) -> &'static str;
+
+    fn compact(&self, disk_map: &DiskMap) -> Compaction;
+}
+
+/// Moves single blocks from the end of the disk to the leftmost free block.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BlockWise;
+
+impl CompactionStrategy for BlockWise {
+    fn name(&self) -> &'static str {
+        "block-wise"
+    }
+
+    fn compact(&self, disk_map: &DiskMap) -> Compaction {
+        let mut files = disk_map.files.clone();
+        let mut free_spaces = disk_map.free_spaces.iter().copied();
+        let mut free_space = free_spaces.next();
+        let mut compaction = Compaction::new(Vec::with_capacity(files.len()));
+
+        while let Some(mut file) = files.pop() {
+            while let Some(free) = free_space.as_mut().filter(|free| free.start < file.start) {
+                let num_moved_blocks = free.length.min(file.length);
+                compaction.files.push(FileExtent {
+                    start: free.start,
+                    length: num_moved_blocks,
+                    ..file
+                });
+                file.length -= num_moved_blocks;
+                compaction.record_move(file.file_id, file.end(), free.start, num_moved_blocks);
+                free.start += num_moved_blocks;
+                free.length -= num_moved_blocks;
+
+                if free.length == 0 {
+                    free_space = free_spaces.next();
+                }
+                if file.length == 0 {
+                    break;
+                }
+            }
+
+            if file.length > 0 {
+                // no free space left of this file, so the files before it stay where they are
+                compaction.files.push(file);
+                compaction.files.append(&mut files);
+            }
+        }
+
+        compaction
+    }
+}
+
+/// Which free extent a whole file moves to, among those left of it that are long enough.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fit {
+    Leftmost,
+    Shortest,
+    Longest,
+}
+
+/// Moves whole files, highest file id first, to a free extent left of them picked by `fit`.
+#[derive(Debug, Clone, Copy)]
+pub struct WholeFile {
+    pub fit: Fit,
+}
+
+impl WholeFile {
+    pub const FIRST_FIT: Self = Self { fit: Fit::Leftmost };
+    pub const BEST_FIT: Self = Self { fit: Fit::Shortest };
+    pub const WORST_FIT: Self = Self { fit: Fit::Longest };
+}
+
+impl CompactionStrategy for WholeFile {
+    fn name(&self) -> &'static str {
+        match self.fit {
+            Fit::Leftmost => "first-fit",
+            Fit::Shortest => "best-fit",
+            Fit::Longest => "worst-fit",
+        }
+    }
+
+    fn compact(&self, disk_map: &DiskMap) -> Compaction {
+        let mut free_space_index = FreeSpaceIndex::new(&disk_map.free_spaces);
+        let mut compaction = Compaction::new(disk_map.files.clone());
+
+        let mut file_indices: Vec<usize> = (0..compaction.files.len()).collect();
+        file_indices.sort_by_key(|file_index| {
+            let file = compaction.files[*file_index];
+            Reverse((file.file_id, file.start))
+        });
+        for file_index in file_indices {
+            let file = compaction.files[file_index];
+            if file.length == 0 {
+                continue;
+            }
+
+            let free_space = match self.fit {
+                Fit::Leftmost => free_space_index.take_leftmost(file.length, file.start),
+                Fit::Shortest => free_space_index.take_shortest(file.length, file.start),
+                Fit::Longest => free_space_index.take_longest(file.length, file.start),
+            };
+            if let Some(free_space) = free_space {
+                compaction.files[file_index].start = free_space.start;
+                compaction.record_move(file.file_id, file.start, free_space.start, file.length);
+                free_space_index.insert(Extent {
+                    start: free_space.start + file.length,
+                    length: free_space.length - file.length,
+                });
+                free_space_index.insert(Extent {
+                    start: file.start,
+                    length: file.length,
+                });
+            }
+        }
+
+        compaction
+    }
+}
+
+/// Rewrites the files back to back from the start of the disk, keeping their order.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Defragment;
+
+impl CompactionStrategy for Defragment {
+    fn name(&self) -> &'static str {
+        "defragment"
+    }
+
+    fn compact(&self, disk_map: &DiskMap) -> Compaction {
+        let mut compaction = Compaction::new(disk_map.files.clone());
+
+        let mut location: usize = 0;
+        for file_index in 0..compaction.files.len() {
+            let file = compaction.files[file_index];
+            if file.start != location && file.length > 0 {
+                compaction.files[file_index].start = location;
+                compaction.record_move(file.file_id, file.start, location, file.length);
+            }
+            location += file.length;
+        }
+
+        compaction
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompactionReport {
+    pub strategy: &'static str,
+    pub checksum: u64,
+    pub fragmentation: usize,
+    pub num_moves: usize,
+    pub num_moved_blocks: usize,
+}
+
+pub fn all_strategies() -> Vec<Box<dyn CompactionStrategy>> {
+    vec![
+        Box::new(BlockWise),
+        Box::new(WholeFile::FIRST_FIT),
+        Box::new(WholeFile::BEST_FIT),
+        Box::new(WholeFile::WORST_FIT),
+        Box::new(Defragment),
+    ]
+}
+
+/// Runs every strategy on the same disk map.
+pub fn compare_strategies(disk_map: &DiskMap) -> Vec<CompactionReport> {
+    all_strategies()
+        .iter()
+        .map(|strategy| {
+            let compaction = strategy.compact(disk_map);
+            CompactionReport {
+                strategy: strategy.name(),
+                checksum: compaction.checksum(),
+                fragmentation: compaction.fragmentation(),
+                num_moves: compaction.num_moves(),
+                num_moved_blocks: compaction.num_moved_blocks(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare_strategies() {
+        let input_file_path = "test_input.txt";
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        let disk_map = DiskMap::parse(&input_text).unwrap();
+
+        let summaries = |disk_map: &DiskMap| -> Vec<(&str, u64, usize, usize)> {
+            compare_strategies(disk_map)
+                .iter()
+                .map(|report| {
+                    (
+                        report.strategy,
+                        report.checksum,
+                        report.fragmentation,
+                        report.num_moves,
+                    )
+                })
+                .collect()
+        };
+        assert_eq!(
+            summaries(&disk_map),
+            [
+                ("block-wise", 1928, 0, 7),
+                ("first-fit", 2858, 5, 4),
+                ("best-fit", 2858, 5, 4),
+                ("worst-fit", 2858, 5, 4),
+                ("defragment", 2453, 0, 9)
+            ]
+        );
+
+        let disk_map = DiskMap::parse("1312112").unwrap();
+        assert_eq!(
+            summaries(&disk_map),
+            [
+                ("block-wise", 19, 0, 2),
+                ("first-fit", 19, 0, 2),
+                ("best-fit", 37, 1, 3),
+                ("worst-fit", 22, 1, 3),
+                ("defragment", 26, 0, 3)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_whole_file_order() {
+        // file 2 comes before file 1 on the disk, but still gets the free block first, and file 1
+        // then moves into the block file 2 left
+        let disk_map = DiskMap::parse("{0}11{2}10{1}1").unwrap();
+        let compaction = WholeFile::FIRST_FIT.compact(&disk_map);
+        assert_eq!(
+            compaction.moves,
+            [
+                FileMove {
+                    file_id: 2,
+                    from: 2,
+                    to: 1,
+                    length: 1
+                },
+                FileMove {
+                    file_id: 1,
+                    from: 3,
+                    to: 2,
+                    length: 1
+                }
+            ]
+        );
+        assert_eq!(compaction.checksum(), 4);
+    }
+}
@@ -0,0 +1,386 @@
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, BinaryHeap},
+};
+
+use aoc_2024_common::{expect_split_once, parse_number, ParseError};
+
+/// Run of consecutive free blocks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Extent {
+    pub start: usize,
+    pub length: usize,
+}
+
+/// Run of consecutive blocks of one file, a file split by compaction has several.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileExtent {
+    pub file_id: u64,
+    pub start: usize,
+    pub length: usize,
+}
+
+impl FileExtent {
+    pub fn end(&self) -> usize {
+        self.start + self.length
+    }
+
+    pub fn checksum(&self) -> u64 {
+        // file id times the sum of the block positions start..end
+        let length = self.length as u64;
+        self.file_id * (length * self.start as u64 + length * length.saturating_sub(1) / 2)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DiskMap {
+    /// In disk order, which for puzzle inputs is also the order of the file ids.
+    pub files: Vec<FileExtent>,
+    /// Free extents in disk order, without empty ones.
+    pub free_spaces: Vec<Extent>,
+}
+
+/// Strips a decimal number enclosed in `open` and `close` from the start of `text`.
+fn strip_delimited_number<'a, T: std::str::FromStr>(
+    input_text: &str,
+    text: &'a str,
+    open: char,
+    close: char,
+) -> Result<Option<(T, &'a str)>, ParseError> {
+    let Some(enclosed_text) = text.strip_prefix(open) else {
+        return Ok(None);
+    };
+    let (number, rest) = expect_split_once(input_text, enclosed_text, &close.to_string())?;
+
+    Ok(Some((parse_number(input_text, number)?, rest)))
+}
+
+impl DiskMap {
+    /// Parses the dense disk map, alternating file and free space lengths. See
+    /// [`crate::encode`] for the extensions beyond the puzzle's single digits.
+    pub fn parse(input_text: &str) -> Result<Self, ParseError> {
+        let mut rest = input_text.trim_end();
+        let mut disk_map = DiskMap::default();
+
+        let mut location: usize = 0;
+        let mut next_file_id: u64 = 0;
+        let mut is_file = true;
+        while !rest.is_empty() {
+            let mut file_id = next_file_id;
+            if is_file {
+                if let Some((explicit_file_id, text)) =
+                    strip_delimited_number(input_text, rest, '{', '}')?
+                {
+                    file_id = explicit_file_id;
+                    rest = text;
+                }
+            }
+
+            let length = match strip_delimited_number(input_text, rest, '(', ')')? {
+                Some((length, text)) => {
+                    rest = text;
+                    length
+                }
+                None => {
+                    let digit = rest.chars().next().and_then(|digit| digit.to_digit(10));
+                    let length =
+                        digit.ok_or_else(|| ParseError::at(input_text, rest, "digit"))? as usize;
+                    rest = &rest[1..];
+                    length
+                }
+            };
+
+            if is_file {
+                disk_map.files.push(FileExtent {
+                    file_id,
+                    start: location,
+                    length,
+                });
+                next_file_id = file_id + 1;
+            } else if length > 0 {
+                disk_map.free_spaces.push(Extent {
+                    start: location,
+                    length,
+                });
+            }
+            location += length;
+            is_file = !is_file;
+        }
+
+        Ok(disk_map)
+    }
+}
+
+/// Longest free extent with its own bucket, the longest a puzzle input has.
+const MAX_BUCKET_LENGTH: usize = 9;
+
+/// Free extents bucketed by length, each bucket a min-heap of start locations. Lengths up to
+/// [`MAX_BUCKET_LENGTH`] have a fixed bucket each, longer ones are looked up by length.
+///
+/// Inserted extents merge with adjacent free extents. The extents merged away stay in their
+/// buckets until they come up and are found missing from `lengths_by_start`.
+#[derive(Debug, Clone, Default)]
+pub struct FreeSpaceIndex {
+    lengths_by_start: BTreeMap<usize, usize>,
+    starts_by_length: [BinaryHeap<Reverse<usize>>; MAX_BUCKET_LENGTH + 1],
+    long_starts_by_length: BTreeMap<usize, BinaryHeap<Reverse<usize>>>,
+}
+
+impl FreeSpaceIndex {
+    pub fn new(free_spaces: &[Extent]) -> Self {
+        let mut free_space_index = Self::default();
+        for free_space in free_spaces {
+            free_space_index.insert(*free_space);
+        }
+
+        free_space_index
+    }
+
+    /// Adds a free extent, merged with the free extents right before and after it.
+    pub fn insert(&mut self, free_space: Extent) {
+        if free_space.length == 0 {
+            return;
+        }
+
+        let Extent {
+            mut start,
+            mut length,
+        } = free_space;
+        if let Some(next_length) = self.lengths_by_start.remove(&(start + length)) {
+            length += next_length;
+        }
+        if let Some((&previous_start, &previous_length)) =
+            self.lengths_by_start.range(..start).next_back()
+        {
+            if previous_start + previous_length == start {
+                self.lengths_by_start.remove(&previous_start);
+                start = previous_start;
+                length += previous_length;
+            }
+        }
+
+        self.lengths_by_start.insert(start, length);
+        if length <= MAX_BUCKET_LENGTH {
+            self.starts_by_length[length].push(Reverse(start));
+        } else {
+            self.long_starts_by_length
+                .entry(length)
+                .or_default()
+                .push(Reverse(start));
+        }
+    }
+
+    /// Drops the extents taken or merged away from the top of the bucket of `length`.
+    fn prune(&mut self, length: usize) {
+        let starts = if length <= MAX_BUCKET_LENGTH {
+            &mut self.starts_by_length[length]
+        } else if let Some(starts) = self.long_starts_by_length.get_mut(&length) {
+            starts
+        } else {
+            return;
+        };
+        while let Some(Reverse(start)) = starts.peek() {
+            if self.lengths_by_start.get(start) == Some(&length) {
+                break;
+            }
+            starts.pop();
+        }
+
+        if length > MAX_BUCKET_LENGTH && starts.is_empty() {
+            self.long_starts_by_length.remove(&length);
+        }
+    }
+
+    /// Leftmost free extent of every length that holds `length` blocks and starts before
+    /// `before`, shortest first.
+    fn candidates(&mut self, length: usize, before: usize) -> impl Iterator<Item = Extent> + '_ {
+        let length = length.max(1);
+        let long_lengths: Vec<usize> = self
+            .long_starts_by_length
+            .range(length..)
+            .map(|(free_length, _)| *free_length)
+            .collect();
+        for free_length in (length..=MAX_BUCKET_LENGTH).chain(long_lengths) {
+            self.prune(free_length);
+        }
+
+        self.starts_by_length
+            .iter()
+            .enumerate()
+            .skip(length)
+            .chain(
+                self.long_starts_by_length
+                    .range(length..)
+                    .map(|(free_length, starts)| (*free_length, starts)),
+            )
+            .filter_map(move |(free_length, starts)| {
+                let start = starts.peek()?.0;
+                (start < before).then_some(Extent {
+                    start,
+                    length: free_length,
+                })
+            })
+    }
+
+    fn take(&mut self, free_space: Option<Extent>) -> Option<Extent> {
+        let free_space = free_space?;
+        self.lengths_by_start.remove(&free_space.start);
+        self.prune(free_space.length);
+        Some(free_space)
+    }
+
+    /// Removes and returns the leftmost free extent that holds `length` blocks and starts
+    /// before `before`.
+    pub fn take_leftmost(&mut self, length: usize, before: usize) -> Option<Extent> {
+        let free_space = self
+            .candidates(length, before)
+            .min_by_key(|free_space| free_space.start);
+        self.take(free_space)
+    }
+
+    /// Like [`Self::take_leftmost`], but takes the shortest free extent that fits.
+    pub fn take_shortest(&mut self, length: usize, before: usize) -> Option<Extent> {
+        let free_space = self.candidates(length, before).next();
+        self.take(free_space)
+    }
+
+    /// Like [`Self::take_leftmost`], but takes the longest free extent.
+    pub fn take_longest(&mut self, length: usize, before: usize) -> Option<Extent> {
+        let free_space = self.candidates(length, before).last();
+        self.take(free_space)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CompactionStrategy, WholeFile};
+
+    #[test]
+    fn test_parse() {
+        let disk_map = DiskMap::parse("12345\n").unwrap();
+        assert_eq!(
+            disk_map.files,
+            [
+                FileExtent {
+                    file_id: 0,
+                    start: 0,
+                    length: 1
+                },
+                FileExtent {
+                    file_id: 1,
+                    start: 3,
+                    length: 3
+                },
+                FileExtent {
+                    file_id: 2,
+                    start: 10,
+                    length: 5
+                }
+            ]
+        );
+        assert_eq!(
+            disk_map.free_spaces,
+            [
+                Extent {
+                    start: 1,
+                    length: 2
+                },
+                Extent {
+                    start: 6,
+                    length: 4
+                }
+            ]
+        );
+        assert_eq!(DiskMap::parse("12x4"), Err(ParseError::new(1, 3, "digit")));
+    }
+
+    #[test]
+    fn test_free_space_index() {
+        let input_file_path = "test_input.txt";
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        let disk_map = DiskMap::parse(&input_text).unwrap();
+        let mut free_space_index = FreeSpaceIndex::new(&disk_map.free_spaces);
+
+        assert_eq!(
+            free_space_index.take_leftmost(2, 40),
+            Some(Extent {
+                start: 2,
+                length: 3
+            })
+        );
+        assert_eq!(
+            free_space_index.take_leftmost(2, 40),
+            Some(Extent {
+                start: 8,
+                length: 3
+            })
+        );
+        assert_eq!(free_space_index.take_leftmost(1, 10), None);
+        assert_eq!(free_space_index.take_leftmost(4, 40), None);
+
+        // the blocks of file 1 join the free extents at 2 and 6 into one of length 7
+        free_space_index.insert(Extent {
+            start: 2,
+            length: 3,
+        });
+        free_space_index.insert(Extent {
+            start: 6,
+            length: 3,
+        });
+        free_space_index.insert(Extent {
+            start: 5,
+            length: 1,
+        });
+        assert_eq!(
+            free_space_index.take_shortest(4, 40),
+            Some(Extent {
+                start: 2,
+                length: 7
+            })
+        );
+    }
+
+    #[test]
+    fn test_free_space_index_long_runs() {
+        let mut free_space_index = FreeSpaceIndex::new(&[]);
+        for (start, length) in [(0, 3), (10, 100_000), (200_000, 12), (300_000, 12)] {
+            free_space_index.insert(Extent { start, length });
+        }
+
+        assert_eq!(
+            free_space_index.take_shortest(10, 400_000),
+            Some(Extent {
+                start: 200_000,
+                length: 12
+            })
+        );
+        assert_eq!(
+            free_space_index.take_longest(2, 400_000),
+            Some(Extent {
+                start: 10,
+                length: 100_000
+            })
+        );
+        assert_eq!(
+            free_space_index.take_leftmost(4, 400_000),
+            Some(Extent {
+                start: 300_000,
+                length: 12
+            })
+        );
+        assert_eq!(free_space_index.take_leftmost(4, 400_000), None);
+        assert_eq!(
+            free_space_index.take_leftmost(1, 400_000),
+            Some(Extent {
+                start: 0,
+                length: 3
+            })
+        );
+
+        let disk_map = DiskMap::parse("1(100000)1").unwrap();
+        let compaction = WholeFile::FIRST_FIT.compact(&disk_map);
+        assert_eq!(compaction.files[1].start, 1);
+        assert_eq!(compaction.checksum(), 1);
+    }
+}
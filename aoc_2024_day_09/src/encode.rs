@@ -0,0 +1,113 @@
+//! Dense disk maps for any disk state.
+//!
+//! The puzzle's format alternates single digit file and free space lengths, with the file ids
+//! counting up from 0. A compacted disk breaks all of that: files are split and out of order,
+//! and runs can be longer than 9 blocks. Splitting a long run into several digits would need
+//! empty files in between, which use up file ids, so the format is extended instead:
+//!
+//! - a length above 9 is written in parentheses, `(12)` for 12 blocks,
+//! - a file whose id is not the one after the previous file's id has it in braces before its
+//!   length, `{9}2` for 2 blocks of file 9.
+//!
+//! Maps without either extension read the same as before.
+
+use std::fmt::Write;
+
+use aoc_2024_common::ParseError;
+
+use crate::{blocks, DiskMap};
+
+fn push_length(text: &mut String, length: usize) {
+    if length <= 9 {
+        write!(text, "{length}").expect("writing to a string");
+    } else {
+        write!(text, "({length})").expect("writing to a string");
+    }
+}
+
+/// Encodes blocks as returned by [`blocks`], `None` being free.
+pub fn encode(blocks: &[Option<u64>]) -> String {
+    let mut text = String::new();
+    let mut next_file_id: u64 = 0;
+    let mut is_file_next = true;
+
+    for run in blocks.chunk_by(|block_1, block_2| block_1 == block_2) {
+        match run[0] {
+            Some(file_id) => {
+                if !is_file_next {
+                    push_length(&mut text, 0);
+                }
+                if file_id != next_file_id {
+                    write!(text, "{{{file_id}}}").expect("writing to a string");
+                }
+                push_length(&mut text, run.len());
+                next_file_id = file_id + 1;
+                is_file_next = false;
+            }
+            None => {
+                if is_file_next {
+                    // an empty file, which takes the next id
+                    push_length(&mut text, 0);
+                    next_file_id += 1;
+                }
+                push_length(&mut text, run.len());
+                is_file_next = true;
+            }
+        }
+    }
+
+    text
+}
+
+pub fn decode(input_text: &str) -> Result<Vec<Option<u64>>, ParseError> {
+    Ok(blocks(&DiskMap::parse(input_text)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::{CompactionStrategy, WholeFile};
+
+    #[test]
+    fn test_encode() {
+        let input_file_path = "test_input.txt";
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        let disk_map = DiskMap::parse(&input_text).unwrap();
+        assert_eq!(encode(&blocks(&disk_map)), input_text.trim_end());
+
+        let compaction = WholeFile::FIRST_FIT.compact(&disk_map);
+        let compacted_blocks = blocks(&DiskMap {
+            files: compaction.files,
+            free_spaces: Vec::new(),
+        });
+        let encoded_text = encode(&compacted_blocks);
+        assert_eq!(encoded_text, "20{9}20{2}10{1}30{7}31{4}21{3}34{5}4145{8}4");
+        assert_eq!(decode(&encoded_text), Ok(compacted_blocks));
+        assert_eq!(encode(&[None, Some(0), Some(0)]), "01{0}2");
+        assert_eq!(encode(&[Some(0); 12]), "(12)");
+        assert_eq!(
+            decode("(12)3{7}(10)"),
+            Ok([vec![Some(0); 12], vec![None; 3], vec![Some(7); 10]].concat())
+        );
+        assert_eq!(decode("1(2"), Err(ParseError::new(1, 4, "`)`")));
+    }
+
+    proptest! {
+        #[test]
+        fn test_decode_encode_round_trip(
+            blocks in prop::collection::vec(prop::option::of(0..20u64), 0..200)
+        ) {
+            prop_assert_eq!(decode(&encode(&blocks)), Ok(blocks.clone()));
+        }
+
+        #[test]
+        fn test_encode_decode_round_trip(
+            lengths in prop::collection::vec(1..=9usize, 0..50)
+        ) {
+            let text: String = lengths.iter().map(|length| length.to_string()).collect();
+            prop_assert_eq!(encode(&decode(&text).unwrap()), text);
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use aoc_2024_common::{ParseError, Solution, SolveError, SolveResult};
+use clap::Args;
+
+mod compaction;
+mod disk_map;
+mod encode;
+mod visualize;
+
+pub use compaction::{
+    all_strategies, compare_strategies, BlockWise, Compaction, CompactionReport,
+    CompactionStrategy, Defragment, FileMove, Fit, WholeFile,
+};
+pub use disk_map::{DiskMap, Extent, FileExtent, FreeSpaceIndex};
+pub use encode::{decode, encode};
+pub use visualize::{blocks, render_layout, replay};
+
+fn parse_input(input_text: &str) -> Result<DiskMap, ParseError> {
+    DiskMap::parse(input_text)
+}
+
+fn process_part1(disk_map: &DiskMap) -> u64 {
+    BlockWise.compact(disk_map).checksum()
+}
+
+fn process_part2(disk_map: &DiskMap) -> u64 {
+    WholeFile::FIRST_FIT.compact(disk_map).checksum()
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct Day09Parameters {
+    /// Print the checksum, fragmentation and moves of every compaction strategy to stderr in part 2
+    #[arg(long)]
+    compare_strategies: bool,
+    /// Print the disk layout after every move of this strategy to stderr in part 2, for example
+    /// `first-fit`
+    #[arg(long)]
+    replay: Option<String>,
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = DiskMap;
+
+    type Parameters = Day09Parameters;
+
+    fn parse_input(input_text: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input_text)
+    }
+
+    fn process_part1(input: &Self::Input, _parameters: &Self::Parameters) -> SolveResult {
+        Ok(process_part1(input).into())
+    }
+
+    fn process_part2(input: &Self::Input, parameters: &Self::Parameters) -> SolveResult {
+        if parameters.compare_strategies {
+            eprintln!("strategy     checksum             fragmentation  moves  moved blocks");
+            for report in compare_strategies(input) {
+                eprintln!(
+                    "{:<12} {:<20} {:<14} {:<6} {}",
+                    report.strategy,
+                    report.checksum,
+                    report.fragmentation,
+                    report.num_moves,
+                    report.num_moved_blocks
+                );
+            }
+        }
+        if let Some(strategy_name) = &parameters.replay {
+            let strategies = all_strategies();
+            let strategy = strategies
+                .iter()
+                .find(|strategy| strategy.name() == strategy_name)
+                .ok_or_else(|| {
+                    let strategy_names: Vec<&str> =
+                        strategies.iter().map(|strategy| strategy.name()).collect();
+                    SolveError::Failed(format!(
+                        "unknown strategy {strategy_name}, expected one of {}",
+                        strategy_names.join(", ")
+                    ))
+                })?;
+            for layout in replay(input, &strategy.compact(input)) {
+                eprintln!("{layout}");
+            }
+        }
+
+        Ok(process_part2(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process_part1() {
+        let input_file_path = "test_input.txt";
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        let input = parse_input(&input_text).unwrap();
+        let result_part1 = process_part1(&input);
+        assert_eq!(result_part1, 1928);
+    }
+
+    #[test]
+    fn test_process_part2() {
+        let input_file_path = "test_input.txt";
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        let input = parse_input(&input_text).unwrap();
+        let result_part2 = process_part2(&input);
+        assert_eq!(result_part2, 2858);
+    }
+}
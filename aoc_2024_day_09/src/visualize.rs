@@ -0,0 +1,118 @@
+use crate::{Compaction, DiskMap, FileMove};
+
+/// File id of every block, `None` for free blocks.
+pub fn blocks(disk_map: &DiskMap) -> Vec<Option<u64>> {
+    let disk_length = disk_map
+        .files
+        .iter()
+        .map(|file| file.end())
+        .chain(
+            disk_map
+                .free_spaces
+                .iter()
+                .map(|free_space| free_space.start + free_space.length),
+        )
+        .max()
+        .unwrap_or_default();
+
+    let mut blocks = vec![None; disk_length];
+    for file in &disk_map.files {
+        blocks[file.start..file.end()].fill(Some(file.file_id));
+    }
+
+    blocks
+}
+
+/// Renders blocks in the puzzle's `00...111...2` notation. With file ids beyond 9 every
+/// block is written as a hexadecimal file id padded to the same width, or as that many dots,
+/// separated by spaces.
+pub fn render_layout(blocks: &[Option<u64>], max_file_id: u64) -> String {
+    let (width, separator) = if max_file_id < 10 {
+        (1, "")
+    } else {
+        (format!("{max_file_id:x}").len(), " ")
+    };
+
+    blocks
+        .iter()
+        .map(|block| match block {
+            Some(file_id) => format!("{file_id:0width$x}"),
+            None => ".".repeat(width),
+        })
+        .collect::<Vec<String>>()
+        .join(separator)
+}
+
+fn apply_move(blocks: &mut [Option<u64>], file_move: &FileMove) {
+    blocks[file_move.from..file_move.from + file_move.length].fill(None);
+    blocks[file_move.to..file_move.to + file_move.length].fill(Some(file_move.file_id));
+}
+
+/// The layout before the compaction and after each of its moves.
+pub fn replay(disk_map: &DiskMap, compaction: &Compaction) -> Vec<String> {
+    let max_file_id = disk_map
+        .files
+        .iter()
+        .map(|file| file.file_id)
+        .max()
+        .unwrap_or_default();
+    let mut blocks = blocks(disk_map);
+    let mut layouts = vec![render_layout(&blocks, max_file_id)];
+    for file_move in &compaction.moves {
+        apply_move(&mut blocks, file_move);
+        layouts.push(render_layout(&blocks, max_file_id));
+    }
+
+    layouts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BlockWise, CompactionStrategy, WholeFile};
+
+    #[test]
+    fn test_replay() {
+        let input_file_path = "test_input.txt";
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        let disk_map = DiskMap::parse(&input_text).unwrap();
+
+        let layouts = replay(&disk_map, &BlockWise.compact(&disk_map));
+        assert_eq!(
+            layouts.first().unwrap(),
+            "00...111...2...333.44.5555.6666.777.888899"
+        );
+        assert_eq!(
+            layouts.last().unwrap(),
+            "0099811188827773336446555566.............."
+        );
+
+        let compaction = WholeFile::FIRST_FIT.compact(&disk_map);
+        assert_eq!(
+            compaction.moves[0],
+            FileMove {
+                file_id: 9,
+                from: 40,
+                to: 2,
+                length: 2
+            }
+        );
+        assert_eq!(
+            replay(&disk_map, &compaction).last().unwrap(),
+            "00992111777.44.333....5555.6666.....8888.."
+        );
+    }
+
+    #[test]
+    fn test_render_layout_hexadecimal() {
+        let disk_map = DiskMap::parse("10101010101010101010121").unwrap();
+        assert_eq!(
+            render_layout(&blocks(&disk_map), 11),
+            "0 1 2 3 4 5 6 7 8 9 a . . b"
+        );
+        assert_eq!(
+            render_layout(&blocks(&disk_map), 16),
+            "00 01 02 03 04 05 06 07 08 09 0a .. .. 0b"
+        );
+    }
+}
@@ -0,0 +1,124 @@
+use aoc_2024_common::{NoParameters, ParseError, Solution, SolveResult};
+use aoc_2024_grid::{Grid, Point};
+use std::collections::{HashMap, HashSet};
+
+fn parse_input(input_text: &str) -> Result<Grid<i8>, ParseError> {
+    Grid::try_parse(input_text, "digit", |ch| {
+        ch.to_digit(10).map(|height| height as i8)
+    })
+}
+
+#[derive(Debug, Clone)]
+struct Trail {
+    start: Point,
+    finish: Point,
+}
+
+impl Trail {
+    pub fn new(location: Point) -> Self {
+        Self {
+            start: location,
+            finish: location,
+        }
+    }
+}
+
+fn get_trailheads(topographic_map: &Grid<i8>) -> Vec<Trail> {
+    topographic_map
+        .iter()
+        .filter(|(_, height)| **height == 0)
+        .map(|(point, _)| Trail::new(point))
+        .collect()
+}
+
+fn continue_trail(topographic_map: &Grid<i8>, trail: &Trail, trail_stack: &mut Vec<Trail>) {
+    let height = topographic_map[trail.finish];
+    for neighbor in topographic_map.neighbors4(trail.finish) {
+        if (topographic_map[neighbor] - height) == 1 {
+            let mut continued_trail = trail.clone();
+            continued_trail.finish = neighbor;
+            trail_stack.push(continued_trail);
+        }
+    }
+}
+
+fn process_part1(topographic_map: &Grid<i8>) -> usize {
+    let mut trail_stack = get_trailheads(topographic_map);
+
+    let mut start_finish_map: HashMap<Point, HashSet<Point>> = HashMap::new();
+    while let Some(trail) = trail_stack.pop() {
+        if topographic_map[trail.finish] == 9 {
+            start_finish_map
+                .entry(trail.start)
+                .or_default()
+                .insert(trail.finish);
+        } else {
+            continue_trail(topographic_map, &trail, &mut trail_stack);
+        }
+    }
+
+    start_finish_map
+        .values()
+        .map(|trail_finishes| trail_finishes.len())
+        .sum()
+}
+
+fn process_part2(topographic_map: &Grid<i8>) -> usize {
+    let mut trail_stack = get_trailheads(topographic_map);
+
+    let mut rating: usize = 0;
+    while let Some(trail) = trail_stack.pop() {
+        if topographic_map[trail.finish] == 9 {
+            rating += 1;
+        } else {
+            continue_trail(topographic_map, &trail, &mut trail_stack);
+        }
+    }
+
+    rating
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Grid<i8>;
+
+    type Parameters = NoParameters;
+
+    fn parse_input(input_text: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input_text)
+    }
+
+    fn process_part1(input: &Self::Input, _parameters: &Self::Parameters) -> SolveResult {
+        Ok(process_part1(input).into())
+    }
+
+    fn process_part2(input: &Self::Input, _parameters: &Self::Parameters) -> SolveResult {
+        Ok(process_part2(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process_part1() {
+        let input_file_path = "test_input.txt";
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        let input = parse_input(&input_text).unwrap();
+        let result_part1 = process_part1(&input);
+        assert_eq!(result_part1, 36);
+    }
+
+    #[test]
+    fn test_process_part2() {
+        let input_file_path = "test_input.txt";
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        let input = parse_input(&input_text).unwrap();
+        let result_part2 = process_part2(&input);
+        assert_eq!(result_part2, 81);
+    }
+}
@@ -0,0 +1,53 @@
+use std::{collections::HashMap, hint::black_box};
+
+use aoc_2024_common::Solution;
+use aoc_2024_day_11::{Day11, NewStones, RuleSet, StoneOverflow};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+type Apply = fn(&RuleSet, u64) -> Result<NewStones, StoneOverflow>;
+
+/// The part 2 histogram, with wrapping counts so that 500 blinks do not overflow.
+fn count_stones(stones: &[u64], num_blinks: usize, rules: &RuleSet, apply: Apply) -> u64 {
+    let mut stone_counter: HashMap<u64, u64> = stones.iter().map(|stone| (*stone, 1)).collect();
+    for _ in 0..num_blinks {
+        let mut new_stones: HashMap<u64, u64> = HashMap::with_capacity(stone_counter.len() * 2);
+        for (stone, amount) in stone_counter {
+            for new_stone in apply(rules, stone).unwrap() {
+                let count = new_stones.entry(new_stone).or_insert(0);
+                *count = count.wrapping_add(amount);
+            }
+        }
+        stone_counter = new_stones;
+    }
+
+    stone_counter
+        .values()
+        .fold(0, |total, count| total.wrapping_add(*count))
+}
+
+fn bench_digit_split(c: &mut Criterion) {
+    let input_text = std::fs::read_to_string("input.txt").unwrap();
+    let stones = Day11::parse_input(&input_text).unwrap();
+    let rules = RuleSet::default();
+
+    for num_blinks in [75, 500] {
+        let mut group = c.benchmark_group(format!("day_11_digit_split_{num_blinks}_blinks"));
+        group.bench_function("strings", |bencher| {
+            bencher.iter(|| {
+                black_box(count_stones(
+                    &stones,
+                    num_blinks,
+                    &rules,
+                    RuleSet::apply_with_strings,
+                ))
+            })
+        });
+        group.bench_function("arithmetic", |bencher| {
+            bencher.iter(|| black_box(count_stones(&stones, num_blinks, &rules, RuleSet::apply)))
+        });
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_digit_split);
+criterion_main!(benches);
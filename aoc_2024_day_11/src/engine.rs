@@ -0,0 +1,227 @@
+use std::collections::{HashMap, HashSet};
+
+use num_bigint::BigUint;
+
+use crate::{RuleSet, StoneOverflow};
+
+/// Counts stones for any number of blinks, sharing the work between queries.
+///
+/// The count of a stone after n blinks is the sum of the counts of its children after n - 1
+/// blinks, and every such count is kept, so later queries reuse whatever earlier ones worked
+/// out. A stone's children are only worked out when it has blinks left, so stones that only
+/// show up after the last blink are never looked at, which matters for rules whose stones keep
+/// growing until they overflow.
+#[derive(Debug, Clone, Default)]
+pub struct StoneEngine {
+    rules: RuleSet,
+    stone_indices: HashMap<u64, usize>,
+    stones: Vec<u64>,
+    /// What every stone turns into, `None` until it is needed.
+    children: Vec<Option<Vec<usize>>>,
+    /// Counts of every stone, by index, after 1, 2, ... blinks, as far as they are known.
+    counts: Vec<Vec<BigUint>>,
+}
+
+impl StoneEngine {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_rules(rules: RuleSet) -> Self {
+        Self {
+            rules,
+            ..Self::default()
+        }
+    }
+
+    fn stone_index(&mut self, stone: u64) -> usize {
+        if let Some(index) = self.stone_indices.get(&stone) {
+            return *index;
+        }
+
+        let index = self.stones.len();
+        self.stone_indices.insert(stone, index);
+        self.stones.push(stone);
+        self.children.push(None);
+        self.counts.push(Vec::new());
+        index
+    }
+
+    fn children(&mut self, index: usize) -> Result<&[usize], StoneOverflow> {
+        if self.children[index].is_none() {
+            let new_stones = self.rules.apply(self.stones[index])?;
+            let children = new_stones
+                .into_iter()
+                .map(|new_stone| self.stone_index(new_stone))
+                .collect();
+            self.children[index] = Some(children);
+        }
+
+        Ok(self.children[index]
+            .as_deref()
+            .expect("children worked out above"))
+    }
+
+    /// Works out the counts of `index` up to `num_blinks` blinks and those of every child it
+    /// needs, with an explicit stack instead of recursing once per blink.
+    fn count_index_after(
+        &mut self,
+        index: usize,
+        num_blinks: usize,
+    ) -> Result<BigUint, StoneOverflow> {
+        if num_blinks == 0 {
+            return Ok(BigUint::from(1u8));
+        }
+
+        let mut pending = vec![(index, num_blinks)];
+        while let Some(&(index, num_blinks)) = pending.last() {
+            let num_blinks_known = self.counts[index].len();
+            if num_blinks_known >= num_blinks {
+                pending.pop();
+                continue;
+            }
+
+            let children = self.children(index)?.to_vec();
+            let num_pending = pending.len();
+            pending.extend(
+                children
+                    .iter()
+                    .filter(|child| self.counts[**child].len() < num_blinks - 1)
+                    .map(|child| (*child, num_blinks - 1)),
+            );
+            if pending.len() > num_pending {
+                continue;
+            }
+
+            for num_blinks_done in num_blinks_known + 1..=num_blinks {
+                let count = if num_blinks_done == 1 {
+                    BigUint::from(children.len())
+                } else {
+                    children
+                        .iter()
+                        .map(|child| &self.counts[*child][num_blinks_done - 2])
+                        .sum()
+                };
+                self.counts[index].push(count);
+            }
+            pending.pop();
+        }
+
+        Ok(self.counts[index][num_blinks - 1].clone())
+    }
+
+    /// Number of stones `stone` turns into after `num_blinks` blinks.
+    pub fn count_after(&mut self, stone: u64, num_blinks: usize) -> Result<BigUint, StoneOverflow> {
+        let index = self.stone_index(stone);
+        self.count_index_after(index, num_blinks)
+    }
+
+    pub fn count_all_after(
+        &mut self,
+        stones: &[u64],
+        num_blinks: usize,
+    ) -> Result<BigUint, StoneOverflow> {
+        stones
+            .iter()
+            .map(|stone| self.count_after(*stone, num_blinks))
+            .sum()
+    }
+
+    /// Number of distinct stones in every generation, from the initial stones up to
+    /// `num_blinks` blinks.
+    pub fn distinct_stones_per_generation(
+        &mut self,
+        stones: &[u64],
+        num_blinks: usize,
+    ) -> Result<Vec<usize>, StoneOverflow> {
+        let mut generation: HashSet<usize> = stones
+            .iter()
+            .map(|stone| self.stone_index(*stone))
+            .collect();
+        let mut distinct_stones = vec![generation.len()];
+        for _ in 0..num_blinks {
+            let mut next_generation = HashSet::new();
+            for index in generation {
+                next_generation.extend(self.children(index)?);
+            }
+            generation = next_generation;
+            distinct_stones.push(generation.len());
+        }
+
+        Ok(distinct_stones)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_after() {
+        let input_file_path = "test_input.txt";
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        let stones = crate::parse_input(&input_text).unwrap();
+
+        let mut engine = StoneEngine::new();
+        assert_eq!(
+            engine.count_all_after(&stones, 6).unwrap(),
+            BigUint::from(22u8)
+        );
+        assert_eq!(
+            engine.count_all_after(&stones, 25).unwrap(),
+            BigUint::from(55312u32)
+        );
+        assert_eq!(engine.count_after(125, 0).unwrap(), BigUint::from(1u8));
+
+        let count = engine.count_after(2024, 1000).unwrap();
+        assert!(count > BigUint::from(u64::MAX));
+        // the children's counts are already known from the query above
+        assert_eq!(engine.counts[engine.stone_indices[&20]].len(), 999);
+        assert_eq!(
+            count,
+            engine.count_after(20, 999).unwrap() + engine.count_after(24, 999).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_distinct_stones_per_generation() {
+        let mut engine = StoneEngine::new();
+        assert_eq!(
+            engine.distinct_stones_per_generation(&[125, 17], 6),
+            Ok(vec![2, 3, 4, 5, 8, 12, 15])
+        );
+
+        let mut engine = StoneEngine::with_rules(RuleSet {
+            multiplier: 3,
+            ..RuleSet::default()
+        });
+        assert_eq!(
+            engine.distinct_stones_per_generation(&[1], 4),
+            Ok(vec![1, 1, 1, 1, 2])
+        );
+        let mut engine = StoneEngine::with_rules(RuleSet {
+            multiplier: u64::MAX,
+            ..RuleSet::default()
+        });
+        assert_eq!(engine.count_after(2, 1), Err(StoneOverflow { stone: 2 }));
+    }
+
+    #[test]
+    fn test_stones_after_last_blink_are_not_expanded() {
+        // the stones keep growing by a factor of 3 and only overflow after dozens of blinks
+        let rules =
+            RuleSet::parse("multiplier = 3; split_into = 2; split_when_digits_multiple_of = 20")
+                .unwrap();
+        let mut engine = StoneEngine::with_rules(rules);
+        assert_eq!(
+            engine.distinct_stones_per_generation(&[125, 17], 3),
+            Ok(vec![2, 2, 2, 2])
+        );
+        assert_eq!(
+            engine.count_all_after(&[125, 17], 3),
+            Ok(BigUint::from(2u8))
+        );
+        assert_eq!(engine.count_after(125, 30), Ok(BigUint::from(1u8)));
+        assert!(engine.count_after(125, 200).is_err());
+    }
+}
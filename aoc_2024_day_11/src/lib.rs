@@ -0,0 +1,121 @@
+use aoc_2024_common::{parse_number, Answer, ParseError, Solution, SolveError, SolveResult};
+use clap::Args;
+use std::collections::HashMap;
+
+mod engine;
+mod rules;
+
+pub use engine::StoneEngine;
+pub use rules::{NewStones, RuleSet, StoneOverflow};
+
+fn parse_input(input_text: &str) -> Result<Vec<u64>, ParseError> {
+    input_text
+        .split_whitespace()
+        .map(|number| parse_number(input_text, number))
+        .collect()
+}
+
+/// Counts with a `u64` histogram, `Ok(None)` once a count overflows.
+fn process_u64(
+    stones: &[u64],
+    num_blinks: usize,
+    rules: &RuleSet,
+) -> Result<Option<u64>, StoneOverflow> {
+    let mut stone_counter: HashMap<u64, u64> = HashMap::new();
+    for stone in stones {
+        *stone_counter.entry(*stone).or_insert(0) += 1;
+    }
+
+    for _ in 0..num_blinks {
+        let mut new_stones: HashMap<u64, u64> = HashMap::with_capacity(stone_counter.len() * 2);
+
+        for (stone, amount) in stone_counter {
+            for new_stone in rules.apply(stone)? {
+                let count = new_stones.entry(new_stone).or_insert(0);
+                let Some(new_count) = count.checked_add(amount) else {
+                    return Ok(None);
+                };
+                *count = new_count;
+            }
+        }
+
+        stone_counter = new_stones;
+    }
+
+    Ok(stone_counter
+        .values()
+        .try_fold(0u64, |total, count| total.checked_add(*count)))
+}
+
+fn process(stones: &[u64], num_blinks: usize, rules: &RuleSet) -> SolveResult {
+    let overflow_error =
+        |overflow: StoneOverflow| SolveError::Failed(format!("stone {} overflows", overflow.stone));
+
+    if let Some(count) = process_u64(stones, num_blinks, rules).map_err(overflow_error)? {
+        return Ok(count.into());
+    }
+
+    let count = StoneEngine::with_rules(*rules)
+        .count_all_after(stones, num_blinks)
+        .map_err(overflow_error)?;
+    Ok(i128::try_from(&count).map_or_else(|_| count.to_string().into(), Answer::Number))
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct Day11Parameters {
+    /// Number of blinks in part 1
+    #[arg(long, default_value_t = 25)]
+    part1_blinks: usize,
+
+    /// Number of blinks in part 2
+    #[arg(long, default_value_t = 75)]
+    part2_blinks: usize,
+
+    /// Stone rules as `key = value` pairs separated by `;`, for example `multiplier = 3`
+    #[arg(long, value_parser = RuleSet::parse, default_value = "")]
+    rules: RuleSet,
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Vec<u64>;
+
+    type Parameters = Day11Parameters;
+
+    fn parse_input(input_text: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input_text)
+    }
+
+    fn process_part1(input: &Self::Input, parameters: &Self::Parameters) -> SolveResult {
+        process(input, parameters.part1_blinks, &parameters.rules)
+    }
+
+    fn process_part2(input: &Self::Input, parameters: &Self::Parameters) -> SolveResult {
+        process(input, parameters.part2_blinks, &parameters.rules)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process() {
+        let input_file_path = "test_input.txt";
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        let input = parse_input(&input_text).unwrap();
+        let rules = RuleSet::default();
+        let result = process(&input, 25, &rules);
+        assert_eq!(result, Ok(Answer::Number(55312)));
+        assert_eq!(
+            process(&[0], 120, &rules),
+            Ok(Answer::Number(3386319961258175912501))
+        );
+
+        let rules = RuleSet::parse("multiplier = 3").unwrap();
+        assert_eq!(process(&[1], 4, &rules), Ok(Answer::Number(2)));
+    }
+}
@@ -0,0 +1,258 @@
+use std::ops::Deref;
+
+use aoc_2024_common::{expect_split_once, parse_number, ParseError};
+
+/// `POWERS_OF_TEN[n]` is 10 to the power of n, for every power that fits into a `u64`.
+const POWERS_OF_TEN: [u64; 20] = {
+    let mut powers_of_ten = [1u64; 20];
+    let mut exponent = 1;
+    while exponent < powers_of_ten.len() {
+        powers_of_ten[exponent] = powers_of_ten[exponent - 1] * 10;
+        exponent += 1;
+    }
+    powers_of_ten
+};
+
+/// Most stones one stone can split into, one per digit of the longest `u64`.
+const MAX_NEW_STONES: usize = POWERS_OF_TEN.len();
+
+/// The stones a stone turns into on a blink, kept inline so that blinking does not allocate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NewStones {
+    stones: [u64; MAX_NEW_STONES],
+    len: usize,
+}
+
+impl NewStones {
+    fn one(stone: u64) -> Self {
+        Self::from_iter([stone])
+    }
+}
+
+impl FromIterator<u64> for NewStones {
+    fn from_iter<I: IntoIterator<Item = u64>>(stones: I) -> Self {
+        let mut new_stones = Self {
+            stones: [0; MAX_NEW_STONES],
+            len: 0,
+        };
+        for stone in stones {
+            new_stones.stones[new_stones.len] = stone;
+            new_stones.len += 1;
+        }
+
+        new_stones
+    }
+}
+
+impl Deref for NewStones {
+    type Target = [u64];
+
+    fn deref(&self) -> &[u64] {
+        &self.stones[..self.len]
+    }
+}
+
+impl IntoIterator for NewStones {
+    type Item = u64;
+    type IntoIter = std::iter::Take<std::array::IntoIter<u64, MAX_NEW_STONES>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.stones.into_iter().take(self.len)
+    }
+}
+
+/// A stone whose value does not fit into a `u64` after applying the rules to `stone`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StoneOverflow {
+    pub stone: u64,
+}
+
+/// What a stone turns into on a blink, trying the rules in this order:
+///
+/// 1. a 0 becomes `zero`,
+/// 2. a stone whose number of digits is a multiple of `split_when_digits_multiple_of` splits
+///    into `split_into` stones, made of equally many of its digits each,
+/// 3. any other stone is multiplied by `multiplier`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RuleSet {
+    pub zero: u64,
+    pub split_when_digits_multiple_of: u32,
+    pub split_into: u32,
+    pub multiplier: u64,
+}
+
+impl Default for RuleSet {
+    fn default() -> Self {
+        Self {
+            zero: 1,
+            split_when_digits_multiple_of: 2,
+            split_into: 2,
+            multiplier: 2024,
+        }
+    }
+}
+
+impl RuleSet {
+    /// Parses `key = value` lines, separated by newlines or `;`, with `#` starting a comment.
+    /// Keys not given keep their default value, for example `multiplier = 3; split_into = 3;
+    /// split_when_digits_multiple_of = 3`.
+    pub fn parse(spec: &str) -> Result<Self, ParseError> {
+        let mut rules = RuleSet::default();
+
+        for line in spec.split(['\n', ';']) {
+            let line = line.split('#').next().unwrap_or_default();
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let (key, value) = expect_split_once(spec, line, "=")?;
+            let (key, value) = (key.trim(), value.trim());
+            match key {
+                "zero" => rules.zero = parse_number(spec, value)?,
+                "split_when_digits_multiple_of" => {
+                    rules.split_when_digits_multiple_of = parse_number(spec, value)?;
+                }
+                "split_into" => rules.split_into = parse_number(spec, value)?,
+                "multiplier" => rules.multiplier = parse_number(spec, value)?,
+                _ => return Err(ParseError::at(
+                    spec,
+                    key,
+                    "one of `zero`, `split_when_digits_multiple_of`, `split_into`, `multiplier`",
+                )),
+            }
+        }
+
+        if (rules.split_into < 2)
+            || !rules
+                .split_when_digits_multiple_of
+                .is_multiple_of(rules.split_into)
+        {
+            return Err(ParseError::after(
+                spec,
+                spec,
+                "`split_into` of at least 2 that divides `split_when_digits_multiple_of`",
+            ));
+        }
+
+        Ok(rules)
+    }
+
+    pub fn apply(&self, stone: u64) -> Result<NewStones, StoneOverflow> {
+        self.apply_with(stone, |stone, num_digits| {
+            let part_length = (num_digits / self.split_into) as usize;
+            let part_modulus = POWERS_OF_TEN[part_length];
+            (0..self.split_into as usize)
+                .rev()
+                .map(|num_parts_after| {
+                    stone / POWERS_OF_TEN[num_parts_after * part_length] % part_modulus
+                })
+                .collect()
+        })
+    }
+
+    /// Same as [`Self::apply`], but splits the digits of the decimal string, which is what the
+    /// arithmetic split is checked and benchmarked against.
+    pub fn apply_with_strings(&self, stone: u64) -> Result<NewStones, StoneOverflow> {
+        self.apply_with(stone, |stone, num_digits| {
+            let stone_string = stone.to_string();
+            let part_length = (num_digits / self.split_into) as usize;
+            (0..self.split_into as usize)
+                .map(|part| {
+                    stone_string[part * part_length..(part + 1) * part_length]
+                        .parse::<u64>()
+                        .unwrap()
+                })
+                .collect()
+        })
+    }
+
+    fn apply_with(
+        &self,
+        stone: u64,
+        split: impl Fn(u64, u32) -> NewStones,
+    ) -> Result<NewStones, StoneOverflow> {
+        if stone == 0 {
+            return Ok(NewStones::one(self.zero));
+        }
+
+        let num_digits = stone.ilog10() + 1;
+        if num_digits.is_multiple_of(self.split_when_digits_multiple_of) {
+            Ok(split(stone, num_digits))
+        } else {
+            let new_stone = stone
+                .checked_mul(self.multiplier)
+                .ok_or(StoneOverflow { stone })?;
+            Ok(NewStones::one(new_stone))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(RuleSet::parse(""), Ok(RuleSet::default()));
+        assert_eq!(
+            RuleSet::parse(
+                "# triples\nmultiplier = 3; split_into = 3\nsplit_when_digits_multiple_of = 6"
+            ),
+            Ok(RuleSet {
+                zero: 1,
+                split_when_digits_multiple_of: 6,
+                split_into: 3,
+                multiplier: 3
+            })
+        );
+        assert_eq!(
+            RuleSet::parse("zero = 1\nsplit = 2"),
+            Err(ParseError::new(
+                2,
+                1,
+                "one of `zero`, `split_when_digits_multiple_of`, `split_into`, `multiplier`"
+            ))
+        );
+        assert!(RuleSet::parse("split_into = 3").is_err());
+    }
+
+    #[test]
+    fn test_apply() {
+        let rules = RuleSet::default();
+        assert_eq!(rules.apply(0).unwrap()[..], [1]);
+        assert_eq!(rules.apply(1000).unwrap()[..], [10, 0]);
+        assert_eq!(rules.apply(125).unwrap()[..], [253000]);
+        assert_eq!(
+            rules.apply(u64::MAX / 1000),
+            Err(StoneOverflow {
+                stone: u64::MAX / 1000
+            })
+        );
+
+        let rules = RuleSet::parse("split_into = 3; split_when_digits_multiple_of = 3").unwrap();
+        assert_eq!(rules.apply(102030).unwrap()[..], [10, 20, 30]);
+
+        for rules in [
+            RuleSet::default(),
+            RuleSet::parse("split_into = 2; split_when_digits_multiple_of = 4").unwrap(),
+            RuleSet::parse("split_into = 3; split_when_digits_multiple_of = 3").unwrap(),
+        ] {
+            for stone in [
+                1,
+                9,
+                10,
+                99,
+                100,
+                1000,
+                102030,
+                123456789,
+                10_000_000_000_000_000_000,
+            ]
+            .into_iter()
+            .chain((0..10_000).map(|step| step * 7919))
+            {
+                assert_eq!(rules.apply(stone), rules.apply_with_strings(stone));
+            }
+        }
+    }
+}
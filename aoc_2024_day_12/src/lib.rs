@@ -0,0 +1,171 @@
+use aoc_2024_common::{NoParameters, ParseError, Solution, SolveResult};
+use aoc_2024_grid::{Direction, Grid, Point};
+use std::{collections::HashMap, ops::AddAssign};
+
+fn parse_input(input_text: &str) -> Result<Grid<char>, ParseError> {
+    Grid::try_parse(input_text, "character", Some)
+}
+
+fn get_region_map(garden_map: &Grid<char>) -> Grid<u32> {
+    let mut next_region_id: u32 = 1;
+    let mut region_map: Grid<u32> = Grid::new(garden_map.num_rows(), garden_map.num_columns(), 0);
+    for point in garden_map.points() {
+        if region_map[point] != 0 {
+            continue;
+        }
+
+        let plant = garden_map[point];
+        let mut region_stack: Vec<Point> = vec![point];
+        while let Some(region_point) = region_stack.pop() {
+            region_map[region_point] = next_region_id;
+
+            for neighbor in garden_map.neighbors4(region_point) {
+                if (plant == garden_map[neighbor]) && (region_map[neighbor] == 0) {
+                    region_stack.push(neighbor);
+                }
+            }
+        }
+        next_region_id += 1;
+    }
+
+    region_map
+}
+
+fn process_part1(garden_map: &Grid<char>) -> u32 {
+    let region_map = get_region_map(garden_map);
+
+    let mut area_map: HashMap<u32, u32> = HashMap::new();
+    let mut perimeter_map: HashMap<u32, u32> = HashMap::new();
+    for (point, region) in region_map.iter() {
+        area_map.entry(*region).or_insert(0).add_assign(1);
+
+        for direction in Direction::CARDINAL {
+            let is_fenced = region_map
+                .neighbor(point, direction)
+                .is_none_or(|neighbor| *region != region_map[neighbor]);
+            if is_fenced {
+                perimeter_map.entry(*region).or_insert(0).add_assign(1);
+            }
+        }
+    }
+
+    area_map
+        .iter()
+        .map(|(region, area)| area * perimeter_map.get(region).unwrap())
+        .sum()
+}
+
+fn process_part2(garden_map: &Grid<char>) -> u32 {
+    let garden_map_with_padding = garden_map.padded(1, '.');
+    let region_map_with_padding = get_region_map(&garden_map_with_padding);
+
+    let mut area_map: HashMap<u32, u32> = HashMap::new();
+    let mut side_map: HashMap<u32, u32> = HashMap::new();
+    for point in garden_map.points() {
+        let padded_point = Point::new(point.row + 1, point.column + 1);
+        let region_at =
+            |direction: Direction| region_map_with_padding[padded_point.step(direction).unwrap()];
+
+        let region = region_map_with_padding[padded_point];
+        let left = region_at(Direction::Left);
+        let right = region_at(Direction::Right);
+        let up = region_at(Direction::Up);
+        let down = region_at(Direction::Down);
+        area_map.entry(region).or_insert(0).add_assign(1);
+
+        // convex corners ┌
+        if (region != left) && (region != up) {
+            side_map.entry(region).or_insert(0).add_assign(2);
+        }
+
+        // convex corners ┐
+        if (region != right) && (region != up) {
+            side_map.entry(region).or_insert(0).add_assign(1);
+        }
+
+        // convex corners └
+        if (region != left) && (region != down) {
+            side_map.entry(region).or_insert(0).add_assign(1);
+        }
+
+        // concav corners ┌
+        if (region != left)
+            && (region != up)
+            && (left == up)
+            && (left == region_at(Direction::UpLeft))
+        {
+            side_map.entry(left).or_insert(0).add_assign(2);
+        }
+
+        // concav corners ┐
+        if (region != right)
+            && (region != up)
+            && (right == up)
+            && (right == region_at(Direction::UpRight))
+        {
+            side_map.entry(right).or_insert(0).add_assign(1);
+        }
+
+        // concav corners └
+        if (region != left)
+            && (region != down)
+            && (left == down)
+            && (left == region_at(Direction::DownLeft))
+        {
+            side_map.entry(left).or_insert(0).add_assign(1);
+        }
+    }
+
+    // remove padding sides
+    side_map.remove(&region_map_with_padding[Point::new(0, 0)]);
+
+    area_map
+        .iter()
+        .map(|(region, area)| area * side_map.get(region).unwrap())
+        .sum()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Grid<char>;
+
+    type Parameters = NoParameters;
+
+    fn parse_input(input_text: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input_text)
+    }
+
+    fn process_part1(input: &Self::Input, _parameters: &Self::Parameters) -> SolveResult {
+        Ok(process_part1(input).into())
+    }
+
+    fn process_part2(input: &Self::Input, _parameters: &Self::Parameters) -> SolveResult {
+        Ok(process_part2(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process_part1() {
+        let input_file_path = "test_input.txt";
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        let input = parse_input(&input_text).unwrap();
+        let result_part1 = process_part1(&input);
+        assert_eq!(result_part1, 1930);
+    }
+
+    #[test]
+    fn test_process_part2() {
+        let input_file_path = "test_input.txt";
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        let input = parse_input(&input_text).unwrap();
+        let result_part2 = process_part2(&input);
+        assert_eq!(result_part2, 1206);
+    }
+}
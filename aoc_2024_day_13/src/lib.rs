@@ -0,0 +1,167 @@
+use aoc_2024_common::{
+    expect_prefix, expect_split_once, expect_token, parse_number, ParseError, Solution, SolveResult,
+};
+use clap::Args;
+
+#[derive(Debug)]
+struct Vertex {
+    x: i64,
+    y: i64,
+}
+
+#[derive(Debug)]
+pub struct ClawMachine {
+    button_a: Vertex,
+    button_b: Vertex,
+    prize: Vertex,
+}
+
+fn parse_vertex(
+    input_text: &str,
+    line: &str,
+    prefix: &str,
+    separator: char,
+) -> Result<Vertex, ParseError> {
+    let coordinates = expect_prefix(input_text, line, prefix)?;
+    let (x, y) = expect_split_once(input_text, coordinates, ", ")?;
+    let x = expect_prefix(input_text, x, &format!("X{separator}"))?;
+    let y = expect_prefix(input_text, y, &format!("Y{separator}"))?;
+
+    Ok(Vertex {
+        x: parse_number(input_text, x)?,
+        y: parse_number(input_text, y)?,
+    })
+}
+
+fn parse_input(input_text: &str) -> Result<Vec<ClawMachine>, ParseError> {
+    input_text
+        .split("\n\n")
+        .map(|claw_machine| {
+            let mut lines = claw_machine.lines();
+            let mut next_vertex = |prefix: &str, separator: char| {
+                let line = expect_token(input_text, claw_machine, lines.next(), prefix)?;
+                parse_vertex(input_text, line, prefix, separator)
+            };
+
+            Ok(ClawMachine {
+                button_a: next_vertex("Button A: ", '+')?,
+                button_b: next_vertex("Button B: ", '+')?,
+                prize: next_vertex("Prize: ", '=')?,
+            })
+        })
+        .collect()
+}
+
+#[derive(Debug)]
+struct Equation {
+    a: i64,
+    b: i64,
+    c: i64,
+}
+
+impl Equation {
+    pub fn mul(&mut self, factor: i64) {
+        self.a *= factor;
+        self.b *= factor;
+        self.c *= factor;
+    }
+
+    pub fn sub(&mut self, other: &Equation) {
+        self.a -= other.a;
+        self.b -= other.b;
+        self.c -= other.c;
+    }
+}
+
+fn get_total_cost(claw_machines: &[ClawMachine], offset: i64) -> i64 {
+    let mut total_cost: i64 = 0;
+
+    for claw_machine in claw_machines {
+        let mut equation_x = Equation {
+            a: claw_machine.button_a.x,
+            b: claw_machine.button_b.x,
+            c: claw_machine.prize.x + offset,
+        };
+        let mut equation_y = Equation {
+            a: claw_machine.button_a.y,
+            b: claw_machine.button_b.y,
+            c: claw_machine.prize.y + offset,
+        };
+
+        let b_x = equation_x.b;
+        let b_y = equation_y.b;
+        equation_x.mul(b_y);
+        equation_y.mul(b_x);
+        equation_y.sub(&equation_x);
+
+        if ((equation_y.c % equation_y.a) != 0) || (equation_y.c.signum() != equation_y.a.signum())
+        {
+            continue;
+        }
+        let a = equation_y.c / equation_y.a;
+
+        equation_x.c -= equation_x.a * a;
+        equation_x.a = 0;
+
+        if ((equation_x.c % equation_x.b) != 0) || (equation_x.c.signum() != equation_x.b.signum())
+        {
+            continue;
+        }
+        let b = equation_x.c / equation_x.b;
+
+        total_cost += a * 3;
+        total_cost += b;
+    }
+
+    total_cost
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct Day13Parameters {
+    /// Offset added to the prize coordinates in part 2
+    #[arg(long, default_value_t = 10000000000000)]
+    part2_offset: i64,
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Vec<ClawMachine>;
+
+    type Parameters = Day13Parameters;
+
+    fn parse_input(input_text: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input_text)
+    }
+
+    fn process_part1(input: &Self::Input, _parameters: &Self::Parameters) -> SolveResult {
+        Ok(get_total_cost(input, 0).into())
+    }
+
+    fn process_part2(input: &Self::Input, parameters: &Self::Parameters) -> SolveResult {
+        Ok(get_total_cost(input, parameters.part2_offset).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_total_cost() {
+        let input_file_path = "test_input.txt";
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        let input = parse_input(&input_text).unwrap();
+        let result_part1 = get_total_cost(&input, 0);
+        assert_eq!(result_part1, 480);
+    }
+
+    #[test]
+    fn test_parse_input_error() {
+        let input_text = "Button A: X+94, Y+34\nButton B: X+22, Y=67\nPrize: X=8400, Y=5400\n";
+        let error = parse_input(input_text).unwrap_err();
+        assert_eq!(error, ParseError::new(2, 17, "`Y+`"));
+    }
+}
@@ -0,0 +1,172 @@
+use aoc_2024_common::{
+    expect_prefix, expect_split_once, parse_number, ParseError, Solution, SolveResult,
+};
+use clap::{builder::RangedU64ValueParser, Args};
+
+#[derive(Debug, Clone)]
+pub struct Robot {
+    position: (i32, i32),
+    velocity: (i32, i32),
+}
+
+impl Robot {
+    pub fn move_once(&mut self, num_rows: i32, num_columns: i32) {
+        self.position.0 = (num_columns + self.position.0 + self.velocity.0) % num_columns;
+        self.position.1 = (num_rows + self.position.1 + self.velocity.1) % num_rows;
+    }
+}
+
+fn parse_pair(input_text: &str, text: &str, prefix: &str) -> Result<(i32, i32), ParseError> {
+    let numbers = expect_prefix(input_text, text, prefix)?;
+    let (x, y) = expect_split_once(input_text, numbers, ",")?;
+    Ok((parse_number(input_text, x)?, parse_number(input_text, y)?))
+}
+
+fn parse_input(input_text: &str) -> Result<Vec<Robot>, ParseError> {
+    input_text
+        .lines()
+        .map(|line| {
+            let (position, velocity) = expect_split_once(input_text, line, " ")?;
+
+            Ok(Robot {
+                position: parse_pair(input_text, position, "p=")?,
+                velocity: parse_pair(input_text, velocity, "v=")?,
+            })
+        })
+        .collect()
+}
+
+fn process_part1(mut robots: Vec<Robot>, num_rows: i32, num_columns: i32, num_seconds: u32) -> i32 {
+    for _ in 0..num_seconds {
+        robots
+            .iter_mut()
+            .for_each(|robot| robot.move_once(num_rows, num_columns));
+    }
+
+    let mut q1_count: i32 = 0;
+    let mut q2_count: i32 = 0;
+    let mut q3_count: i32 = 0;
+    let mut q4_count: i32 = 0;
+    let mid_x = num_columns / 2;
+    let mid_y = num_rows / 2;
+    for robot in robots {
+        if (robot.position.0 < mid_x) && (robot.position.1 < mid_y) {
+            q1_count += 1;
+        } else if (robot.position.0 > mid_x) && (robot.position.1 < mid_y) {
+            q2_count += 1;
+        } else if (robot.position.0 > mid_x) && (robot.position.1 > mid_y) {
+            q3_count += 1;
+        } else if (robot.position.0 < mid_x) && (robot.position.1 > mid_y) {
+            q4_count += 1;
+        }
+    }
+
+    q1_count * q2_count * q3_count * q4_count
+}
+
+fn process_part2(mut robots: Vec<Robot>, num_rows: usize, num_columns: usize) -> i32 {
+    let mut time: i32 = 0;
+
+    let mut grid: Vec<Vec<u8>> = vec![vec![0; num_columns]; num_rows];
+    'time_loop: loop {
+        // clear grid
+        robots
+            .iter_mut()
+            .for_each(|robot| grid[robot.position.1 as usize][robot.position.0 as usize] = 0);
+
+        robots.iter_mut().for_each(|robot| {
+            robot.move_once(num_rows as i32, num_columns as i32);
+            grid[robot.position.1 as usize][robot.position.0 as usize] = 1;
+        });
+        time += 1;
+
+        let mut counter: usize = 0;
+        for row_index in 0..num_rows {
+            for column_index in 0..num_columns {
+                if grid[row_index][column_index] != 0 {
+                    counter += 1;
+                } else {
+                    counter = 0;
+                }
+
+                if counter >= 8 {
+                    break 'time_loop;
+                }
+            }
+        }
+    }
+
+    time
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct Day14Parameters {
+    /// Height of the room
+    #[arg(
+        long,
+        default_value_t = 103,
+        value_parser = RangedU64ValueParser::<usize>::new().range(1..=i32::MAX as u64)
+    )]
+    num_rows: usize,
+
+    /// Width of the room
+    #[arg(
+        long,
+        default_value_t = 101,
+        value_parser = RangedU64ValueParser::<usize>::new().range(1..=i32::MAX as u64)
+    )]
+    num_columns: usize,
+
+    /// Seconds the robots move in part 1
+    #[arg(long, default_value_t = 100)]
+    num_seconds: u32,
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Vec<Robot>;
+
+    type Parameters = Day14Parameters;
+
+    fn parse_input(input_text: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input_text)
+    }
+
+    fn process_part1(input: &Self::Input, parameters: &Self::Parameters) -> SolveResult {
+        Ok(process_part1(
+            input.clone(),
+            parameters.num_rows as i32,
+            parameters.num_columns as i32,
+            parameters.num_seconds,
+        )
+        .into())
+    }
+
+    fn process_part2(input: &Self::Input, parameters: &Self::Parameters) -> SolveResult {
+        Ok(process_part2(input.clone(), parameters.num_rows, parameters.num_columns).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process_part1() {
+        let input_file_path = "test_input.txt";
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        let input = parse_input(&input_text).unwrap();
+        let result_part1 = process_part1(input, 7, 11, 100);
+        assert_eq!(result_part1, 12);
+    }
+
+    #[test]
+    fn test_parse_input_error() {
+        let input_text = "p=0,4 v=3,-3\np=6,3 v=-1-3\n";
+        let error = parse_input(input_text).unwrap_err();
+        assert_eq!(error, ParseError::new(2, 13, "`,`"));
+    }
+}
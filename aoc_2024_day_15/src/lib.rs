@@ -0,0 +1,254 @@
+use aoc_2024_common::{expect_split_once, NoParameters, ParseError, Solution, SolveResult};
+use aoc_2024_grid::{Direction, Grid, Point};
+use std::collections::HashSet;
+
+const WALL: char = '#';
+const BOX: char = 'O';
+const ROBOT: char = '@';
+const FREE: char = '.';
+const UP: char = '^';
+const DOWN: char = 'v';
+const LEFT: char = '<';
+const RIGHT: char = '>';
+const BOX_LEFT: char = '[';
+const BOX_RIGHT: char = ']';
+
+#[derive(Debug, Clone)]
+pub struct Warehouse {
+    floor_map: Grid<char>,
+    robot_location: Point,
+    robot_movement_sequence: Vec<Direction>,
+}
+
+fn parse_robot_movement_sequence(
+    input_text: &str,
+    movement_text: &str,
+) -> Result<Vec<Direction>, ParseError> {
+    movement_text
+        .char_indices()
+        .filter(|(_, ch)| *ch != '\n')
+        .map(|(index, ch)| match ch {
+            UP => Ok(Direction::Up),
+            DOWN => Ok(Direction::Down),
+            LEFT => Ok(Direction::Left),
+            RIGHT => Ok(Direction::Right),
+            _ => Err(ParseError::at(
+                input_text,
+                &movement_text[index..],
+                "one of `^v<>`",
+            )),
+        })
+        .collect()
+}
+
+fn parse_input_part1(input_text: &str) -> Result<Warehouse, ParseError> {
+    let (floor_text, movement_text) = expect_split_once(input_text, input_text, "\n\n")?;
+
+    let floor_map = Grid::try_parse(floor_text, "one of `#O@.`", |ch| {
+        matches!(ch, WALL | BOX | ROBOT | FREE).then_some(ch)
+    })?;
+    let Some(robot_location) = floor_map.find(|ch| *ch == ROBOT) else {
+        return Err(ParseError::after(input_text, input_text, "a robot `@`"));
+    };
+    let robot_movement_sequence = parse_robot_movement_sequence(input_text, movement_text)?;
+
+    Ok(Warehouse {
+        floor_map,
+        robot_location,
+        robot_movement_sequence,
+    })
+}
+
+fn parse_input_part2(input_text: &str) -> Result<Warehouse, ParseError> {
+    let warehouse = parse_input_part1(input_text)?;
+
+    let floor_map = Grid::from_rows(
+        warehouse
+            .floor_map
+            .rows()
+            .map(|row| {
+                row.iter()
+                    .flat_map(|ch| match *ch {
+                        WALL => [WALL, WALL],
+                        BOX => [BOX_LEFT, BOX_RIGHT],
+                        ROBOT => [ROBOT, FREE],
+                        _ => [FREE, FREE],
+                    })
+                    .collect()
+            })
+            .collect(),
+    );
+    let robot_location = floor_map
+        .find(|ch| *ch == ROBOT)
+        .expect("robot found in the part 1 map");
+
+    Ok(Warehouse {
+        floor_map,
+        robot_location,
+        robot_movement_sequence: warehouse.robot_movement_sequence,
+    })
+}
+
+fn step(location: Point, direction: Direction) -> Point {
+    location
+        .step(direction)
+        .expect("warehouse is surrounded by walls")
+}
+
+fn shift_horizontally(warehouse: &mut Warehouse, direction: Direction) {
+    let mut shift_location = warehouse.robot_location;
+
+    while !matches!(warehouse.floor_map[shift_location], FREE | WALL) {
+        shift_location = step(shift_location, direction);
+    }
+
+    if warehouse.floor_map[shift_location] == FREE {
+        while shift_location != warehouse.robot_location {
+            let swap_location = step(shift_location, direction.opposite());
+            warehouse.floor_map.swap(swap_location, shift_location);
+            shift_location = swap_location;
+        }
+
+        warehouse.robot_location = step(warehouse.robot_location, direction);
+    }
+}
+
+fn get_gps_coordinate_sum(warehouse: &Warehouse, box_symbol: char) -> usize {
+    warehouse
+        .floor_map
+        .iter()
+        .filter(|(_, warehouse_location)| **warehouse_location == box_symbol)
+        .map(|(location, _)| location.column + (location.row * 100))
+        .sum()
+}
+
+fn process_part1(mut warehouse: Warehouse) -> usize {
+    for direction in warehouse.robot_movement_sequence.clone() {
+        shift_horizontally(&mut warehouse, direction);
+    }
+
+    get_gps_coordinate_sum(&warehouse, BOX)
+}
+
+fn process_part2(mut warehouse: Warehouse) -> usize {
+    for direction in warehouse.robot_movement_sequence.clone() {
+        if matches!(direction, Direction::Left | Direction::Right) {
+            shift_horizontally(&mut warehouse, direction);
+        } else {
+            let floor_map = &mut warehouse.floor_map;
+            let mut shift_row = warehouse.robot_location.row;
+            let mut is_blocked = false;
+            let mut column_stack = vec![HashSet::from([warehouse.robot_location.column])];
+            loop {
+                let src_row = shift_row;
+                shift_row = step(Point::new(shift_row, 0), direction).row;
+
+                let mut shift_columns = HashSet::new();
+                for src_column in column_stack.last().unwrap() {
+                    if matches!(
+                        floor_map[Point::new(src_row, *src_column)],
+                        ROBOT | BOX_LEFT | BOX_RIGHT
+                    ) {
+                        match floor_map[Point::new(shift_row, *src_column)] {
+                            BOX_LEFT => {
+                                shift_columns.insert(*src_column);
+                                shift_columns.insert(src_column + 1);
+                            }
+                            BOX_RIGHT => {
+                                shift_columns.insert(*src_column);
+                                shift_columns.insert(src_column - 1);
+                            }
+                            WALL => is_blocked = true,
+                            _ => (),
+                        }
+                    }
+                }
+
+                let is_free = shift_columns.iter().all(|shift_column| {
+                    !matches!(
+                        floor_map[Point::new(shift_row, *shift_column)],
+                        BOX_LEFT | BOX_RIGHT | WALL
+                    )
+                });
+                if is_free {
+                    break;
+                }
+
+                column_stack.push(shift_columns);
+            }
+
+            if !is_blocked {
+                while let Some(shift_columns) = column_stack.pop() {
+                    let swap_row = step(Point::new(shift_row, 0), direction.opposite()).row;
+
+                    for shift_column in shift_columns {
+                        floor_map.swap(
+                            Point::new(swap_row, shift_column),
+                            Point::new(shift_row, shift_column),
+                        );
+                    }
+
+                    shift_row = swap_row;
+                }
+
+                warehouse.robot_location = step(warehouse.robot_location, direction);
+            }
+        }
+    }
+
+    get_gps_coordinate_sum(&warehouse, BOX_LEFT)
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = (Warehouse, Warehouse);
+
+    type Parameters = NoParameters;
+
+    fn parse_input(input_text: &str) -> Result<Self::Input, ParseError> {
+        Ok((
+            parse_input_part1(input_text)?,
+            parse_input_part2(input_text)?,
+        ))
+    }
+
+    fn process_part1(input: &Self::Input, _parameters: &Self::Parameters) -> SolveResult {
+        Ok(process_part1(input.0.clone()).into())
+    }
+
+    fn process_part2(input: &Self::Input, _parameters: &Self::Parameters) -> SolveResult {
+        Ok(process_part2(input.1.clone()).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("test_input_small.txt" => 2028)]
+    #[test_case("test_input_big.txt" => 10092)]
+    fn test_process_part1(input_file_path: &str) -> usize {
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        let input = parse_input_part1(&input_text).unwrap();
+        process_part1(input)
+    }
+
+    #[test]
+    fn test_process_part2() {
+        let input_file_path = "test_input_big.txt";
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        let input = parse_input_part2(&input_text).unwrap();
+        let result_part2 = process_part2(input);
+        assert_eq!(result_part2, 9021);
+    }
+
+    #[test]
+    fn test_parse_input_robot() {
+        let error = parse_input_part1("###\n#.#\n###\n\n<").unwrap_err();
+        assert_eq!(error, ParseError::new(5, 2, "a robot `@`"));
+    }
+}
@@ -0,0 +1,40 @@
+use std::hint::black_box;
+
+use aoc_2024_common::Solution;
+use aoc_2024_day_17::{Day17, Registers};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+/// Runs the puzzle program for as many register A candidates as the part 2 search tries.
+fn bench_interpreters(c: &mut Criterion) {
+    let input_text = std::fs::read_to_string("input.txt").unwrap();
+    let computer = Day17::parse_input(&input_text).unwrap();
+    let compiled_program = computer.compile();
+    let registers: Vec<Registers> = (0..256u64)
+        .map(|register_a| Registers {
+            a: register_a << 40,
+            b: 0,
+            c: 0,
+        })
+        .collect();
+
+    let mut group = c.benchmark_group("day_17_interpreter");
+    group.bench_function("match_opcode", |bencher| {
+        bencher.iter(|| {
+            for registers in &registers {
+                let mut computer = computer.clone().with_registers(*registers);
+                black_box(computer.run_program()).unwrap();
+            }
+        })
+    });
+    group.bench_function("compiled", |bencher| {
+        bencher.iter(|| {
+            for registers in &registers {
+                black_box(compiled_program.run(*registers, computer.step_limit())).unwrap();
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_interpreters);
+criterion_main!(benches);
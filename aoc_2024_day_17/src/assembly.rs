@@ -0,0 +1,221 @@
+use std::fmt;
+
+use aoc_2024_common::{expect_token, parse_number, ParseError};
+
+use crate::ComputerError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Opcode {
+    Adv,
+    Bxl,
+    Bst,
+    Jnz,
+    Bxc,
+    Out,
+    Bdv,
+    Cdv,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OperandKind {
+    Literal,
+    Combo,
+    Ignored,
+}
+
+impl Opcode {
+    /// Indexed by the opcode's 3-bit value.
+    pub const ALL: [Opcode; 8] = [
+        Opcode::Adv,
+        Opcode::Bxl,
+        Opcode::Bst,
+        Opcode::Jnz,
+        Opcode::Bxc,
+        Opcode::Out,
+        Opcode::Bdv,
+        Opcode::Cdv,
+    ];
+
+    pub fn from_byte(byte: u8) -> Option<Self> {
+        Self::ALL.get(byte as usize).copied()
+    }
+
+    pub fn mnemonic(&self) -> &'static str {
+        match self {
+            Opcode::Adv => "adv",
+            Opcode::Bxl => "bxl",
+            Opcode::Bst => "bst",
+            Opcode::Jnz => "jnz",
+            Opcode::Bxc => "bxc",
+            Opcode::Out => "out",
+            Opcode::Bdv => "bdv",
+            Opcode::Cdv => "cdv",
+        }
+    }
+
+    fn operand_kind(&self) -> OperandKind {
+        match self {
+            Opcode::Bxl | Opcode::Jnz => OperandKind::Literal,
+            Opcode::Bxc => OperandKind::Ignored,
+            _ => OperandKind::Combo,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    pub opcode: Opcode,
+    pub operand: u8,
+}
+
+impl Instruction {
+    fn operand_text(&self) -> String {
+        match (self.opcode.operand_kind(), self.operand) {
+            (OperandKind::Combo, 4) => "a".to_string(),
+            (OperandKind::Combo, 5) => "b".to_string(),
+            (OperandKind::Combo, 6) => "c".to_string(),
+            _ => self.operand.to_string(),
+        }
+    }
+
+    /// Meaning of the instruction as pseudo code.
+    pub fn description(&self) -> String {
+        let operand = self.operand_text();
+        match self.opcode {
+            Opcode::Adv => format!("a = a >> {operand}"),
+            Opcode::Bxl => format!("b = b ^ {operand}"),
+            Opcode::Bst => format!("b = {operand} & 7"),
+            Opcode::Jnz => format!("jump to {operand} if a != 0"),
+            Opcode::Bxc => "b = b ^ c".to_string(),
+            Opcode::Out => format!("out({operand} & 7)"),
+            Opcode::Bdv => format!("b = a >> {operand}"),
+            Opcode::Cdv => format!("c = a >> {operand}"),
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.opcode.mnemonic(), self.operand_text())
+    }
+}
+
+pub fn decode(program: &[u8]) -> Result<Vec<Instruction>, ComputerError> {
+    program
+        .chunks(2)
+        .map(|chunk| {
+            let [opcode, operand] = *chunk else {
+                return Err(ComputerError::MissingOperand);
+            };
+            let opcode = Opcode::from_byte(opcode).ok_or(ComputerError::InvalidOpcode)?;
+            let is_reserved_combo_operand =
+                (opcode.operand_kind() == OperandKind::Combo) && (operand == 7);
+            if (operand > 7) || is_reserved_combo_operand {
+                return Err(ComputerError::InvalidOperand);
+            }
+
+            Ok(Instruction { opcode, operand })
+        })
+        .collect()
+}
+
+/// Renders `program` with one instruction per line, followed by its meaning as comment.
+pub fn disassemble(program: &[u8]) -> Result<String, ComputerError> {
+    Ok(decode(program)?
+        .iter()
+        .map(|instruction| {
+            format!(
+                "{:<6} ; {}",
+                instruction.to_string(),
+                instruction.description()
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n"))
+}
+
+fn parse_operand(source: &str, kind: OperandKind, operand: &str) -> Result<u8, ParseError> {
+    match (kind, operand) {
+        (OperandKind::Combo, "a") => Ok(4),
+        (OperandKind::Combo, "b") => Ok(5),
+        (OperandKind::Combo, "c") => Ok(6),
+        (OperandKind::Combo, _) => parse_number::<u8>(source, operand)
+            .ok()
+            .filter(|operand| *operand <= 3)
+            .ok_or_else(|| ParseError::at(source, operand, "0 to 3 or register a, b or c")),
+        _ => parse_number::<u8>(source, operand)
+            .ok()
+            .filter(|operand| *operand <= 7)
+            .ok_or_else(|| ParseError::at(source, operand, "0 to 7")),
+    }
+}
+
+/// Parses the textual form written by [`disassemble`], text after `;` is a comment.
+pub fn assemble(source: &str) -> Result<Vec<u8>, ParseError> {
+    let mut program = Vec::new();
+
+    for line in source.lines() {
+        let code = line.split(';').next().unwrap_or_default().trim();
+        let mut tokens = code.split_whitespace();
+        let Some(mnemonic) = tokens.next() else {
+            continue;
+        };
+
+        let opcode = Opcode::ALL
+            .into_iter()
+            .find(|opcode| opcode.mnemonic() == mnemonic)
+            .ok_or_else(|| ParseError::at(source, mnemonic, "mnemonic"))?;
+        let operand = match (opcode.operand_kind(), tokens.next()) {
+            (OperandKind::Ignored, None) => 0,
+            (kind, operand) => {
+                let operand = expect_token(source, code, operand, "operand")?;
+                parse_operand(source, kind, operand)?
+            }
+        };
+        if let Some(token) = tokens.next() {
+            return Err(ParseError::at(source, token, "end of line"));
+        }
+
+        program.extend([opcode as u8, operand]);
+    }
+
+    Ok(program)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let input_file_path = "input.txt";
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        let program = crate::parse_input(&input_text).unwrap().program;
+
+        let source = disassemble(&program).unwrap();
+        assert!(source.starts_with("bst a  ; b = a & 7\n"));
+        assert_eq!(assemble(&source).unwrap(), program);
+    }
+
+    #[test]
+    fn test_assemble_errors() {
+        assert_eq!(
+            assemble("bxc\n\nout a ; comment").unwrap(),
+            vec![4, 0, 5, 4]
+        );
+        assert_eq!(
+            assemble("adv 3\nmul 2").unwrap_err(),
+            ParseError::new(2, 1, "mnemonic")
+        );
+        assert_eq!(
+            assemble("bst 4").unwrap_err(),
+            ParseError::new(1, 5, "0 to 3 or register a, b or c")
+        );
+        assert_eq!(
+            assemble("jnz").unwrap_err(),
+            ParseError::new(1, 4, "operand")
+        );
+        assert_eq!(decode(&[0, 7]), Err(ComputerError::InvalidOperand));
+        assert_eq!(decode(&[3, 0, 1]), Err(ComputerError::MissingOperand));
+    }
+}
@@ -0,0 +1,197 @@
+use std::ops::ControlFlow;
+
+use crate::{shift_right, ComputerError, LoopDetector, MachineState, OutputSink, Registers};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operand {
+    Literal(u64),
+    A,
+    B,
+    C,
+    Reserved,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operation {
+    Adv(Operand),
+    Bxl(u64),
+    Bst(Operand),
+    Jnz(usize),
+    Bxc,
+    Out(Operand),
+    Bdv(Operand),
+    Cdv(Operand),
+    Invalid,
+}
+
+/// A program decoded once, with combo operands resolved to literals or registers.
+///
+/// `jnz` may jump to odd offsets, so the program is decoded starting at every offset instead of
+/// every other one, and running it never looks at the raw bytes again. Operations are dispatched
+/// with a `match` on the decoded enum, which benchmarked faster than a boxed closure per offset.
+#[derive(Debug, Clone)]
+pub struct CompiledProgram {
+    operations: Vec<Operation>,
+}
+
+#[inline]
+fn combo_value(operand: Operand, registers: &Registers) -> Result<u64, ComputerError> {
+    match operand {
+        Operand::Literal(value) => Ok(value),
+        Operand::A => Ok(registers.a),
+        Operand::B => Ok(registers.b),
+        Operand::C => Ok(registers.c),
+        Operand::Reserved => Err(ComputerError::InvalidOperand),
+    }
+}
+
+impl CompiledProgram {
+    pub fn compile(program: &[u8]) -> Self {
+        let operations = program
+            .windows(2)
+            .map(|window| {
+                let operand = window[1];
+                let combo_operand = match operand {
+                    0..=3 => Operand::Literal(operand as u64),
+                    4 => Operand::A,
+                    5 => Operand::B,
+                    6 => Operand::C,
+                    _ => Operand::Reserved,
+                };
+
+                match window[0] {
+                    0 => Operation::Adv(combo_operand),
+                    1 => Operation::Bxl(operand as u64),
+                    2 => Operation::Bst(combo_operand),
+                    3 => Operation::Jnz(operand as usize),
+                    4 => Operation::Bxc,
+                    5 => Operation::Out(combo_operand),
+                    6 => Operation::Bdv(combo_operand),
+                    7 => Operation::Cdv(combo_operand),
+                    _ => Operation::Invalid,
+                }
+            })
+            .collect();
+
+        Self { operations }
+    }
+
+    /// Same as [`crate::Computer::run_program`], including the step limit and loop detection.
+    pub fn run(&self, registers: Registers, step_limit: u64) -> Result<Vec<u8>, ComputerError> {
+        let mut output = Vec::new();
+        self.run_with_sink(registers, step_limit, &mut output)?;
+        Ok(output)
+    }
+
+    pub fn run_with_sink(
+        &self,
+        registers: Registers,
+        step_limit: u64,
+        sink: &mut impl OutputSink,
+    ) -> Result<(), ComputerError> {
+        let mut registers = registers;
+        let mut instruction_pointer: usize = 0;
+        let mut loop_detector = LoopDetector::new(step_limit);
+
+        while let Some(operation) = self.operations.get(instruction_pointer) {
+            loop_detector.observe(MachineState {
+                registers,
+                instruction_pointer,
+            })?;
+
+            let mut output = None;
+            match *operation {
+                Operation::Adv(operand) => {
+                    registers.a = shift_right(registers.a, combo_value(operand, &registers)?);
+                    instruction_pointer += 2;
+                }
+                Operation::Bxl(value) => {
+                    registers.b ^= value;
+                    instruction_pointer += 2;
+                }
+                Operation::Bst(operand) => {
+                    registers.b = combo_value(operand, &registers)? & 7;
+                    instruction_pointer += 2;
+                }
+                Operation::Jnz(target) => {
+                    if registers.a != 0 {
+                        instruction_pointer = target;
+                    } else {
+                        instruction_pointer += 2;
+                    }
+                }
+                Operation::Bxc => {
+                    registers.b ^= registers.c;
+                    instruction_pointer += 2;
+                }
+                Operation::Out(operand) => {
+                    output = Some((combo_value(operand, &registers)? & 7) as u8);
+                    instruction_pointer += 2;
+                }
+                Operation::Bdv(operand) => {
+                    registers.b = shift_right(registers.a, combo_value(operand, &registers)?);
+                    instruction_pointer += 2;
+                }
+                Operation::Cdv(operand) => {
+                    registers.c = shift_right(registers.a, combo_value(operand, &registers)?);
+                    instruction_pointer += 2;
+                }
+                Operation::Invalid => return Err(ComputerError::InvalidOpcode),
+            }
+            if let Some(ControlFlow::Break(())) = output.map(|value| sink.emit(value)) {
+                return Ok(());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Computer;
+
+    fn assert_same_output(computer: &Computer) {
+        let compiled_program = computer.compile();
+        assert_eq!(
+            compiled_program.run(computer.registers(), computer.step_limit),
+            computer.clone().run_program()
+        );
+    }
+
+    #[test]
+    fn test_same_output_as_run_program() {
+        for input_file_path in ["test_input.txt", "input.txt"] {
+            let input_text = std::fs::read_to_string(input_file_path).unwrap();
+            let computer = crate::parse_input(&input_text).unwrap();
+            for register_a in [0, 1, 7, 729, 117440, 1 << 40, u64::MAX] {
+                assert_same_output(&computer.clone().with_registers(Registers {
+                    a: register_a,
+                    b: 0,
+                    c: 0,
+                }));
+            }
+        }
+    }
+
+    #[test]
+    fn test_same_errors_as_run_program() {
+        let input_text = std::fs::read_to_string("test_input.txt").unwrap();
+        let computer = crate::parse_input(&input_text).unwrap();
+        for program in [
+            vec![1, 3, 3, 0],
+            vec![0, 1, 3, 0],
+            vec![5, 7],
+            vec![3, 1, 5],
+            vec![2, 4, 3, 1, 4],
+            // shifts by register A, 729 bits
+            vec![7, 4, 5, 6, 0, 3, 3, 0],
+            vec![6, 4, 5, 5, 0, 4, 3, 0],
+        ] {
+            let mut computer = computer.clone().with_step_limit(20);
+            computer.program = program;
+            assert_same_output(&computer);
+        }
+    }
+}
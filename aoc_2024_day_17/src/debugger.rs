@@ -0,0 +1,282 @@
+use std::{collections::HashSet, fmt};
+
+use serde::{Serialize, Serializer};
+
+use crate::{Computer, ComputerError, Instruction, LoopDetector};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Register {
+    A,
+    B,
+    C,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub struct Registers {
+    pub a: u64,
+    pub b: u64,
+    pub c: u64,
+}
+
+impl fmt::Display for Registers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a={} b={} c={}", self.a, self.b, self.c)
+    }
+}
+
+impl Serialize for Instruction {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// One executed instruction with the registers before and after it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TraceEntry {
+    pub instruction_pointer: usize,
+    pub instruction: Instruction,
+    pub before: Registers,
+    pub after: Registers,
+    pub output: Option<u8>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct Trace {
+    pub entries: Vec<TraceEntry>,
+}
+
+impl Trace {
+    /// One line per executed instruction, meant for diffing two runs.
+    pub fn to_text(&self) -> String {
+        self.entries
+            .iter()
+            .map(|entry| {
+                let mut line = format!(
+                    "{:>3}: {:<6} {} -> {}",
+                    entry.instruction_pointer,
+                    entry.instruction.to_string(),
+                    entry.before,
+                    entry.after
+                );
+                if let Some(output) = entry.output {
+                    line.push_str(&format!(" out={output}"));
+                }
+                line
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("trace is serializable")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StopReason {
+    Halted,
+    Breakpoint(usize),
+    Watch {
+        register: Register,
+        old_value: u64,
+        new_value: u64,
+    },
+}
+
+/// Executes a [`Computer`] step by step, stopping at breakpoints and changes of watched registers.
+#[derive(Debug, Clone)]
+pub struct Debugger {
+    computer: Computer,
+    breakpoints: HashSet<usize>,
+    watches: Vec<Register>,
+    output: Vec<u8>,
+    trace: Option<Trace>,
+}
+
+impl Debugger {
+    pub fn new(computer: Computer) -> Self {
+        Self {
+            computer,
+            breakpoints: HashSet::new(),
+            watches: Vec::new(),
+            output: Vec::new(),
+            trace: None,
+        }
+    }
+
+    pub fn computer(&self) -> &Computer {
+        &self.computer
+    }
+
+    pub fn output(&self) -> &[u8] {
+        &self.output
+    }
+
+    pub fn trace(&self) -> Option<&Trace> {
+        self.trace.as_ref()
+    }
+
+    pub fn add_breakpoint(&mut self, instruction_pointer: usize) {
+        self.breakpoints.insert(instruction_pointer);
+    }
+
+    pub fn remove_breakpoint(&mut self, instruction_pointer: usize) -> bool {
+        self.breakpoints.remove(&instruction_pointer)
+    }
+
+    pub fn watch(&mut self, register: Register) {
+        if !self.watches.contains(&register) {
+            self.watches.push(register);
+        }
+    }
+
+    /// Records every instruction executed from now on.
+    pub fn enable_trace(&mut self) {
+        self.trace.get_or_insert_with(Trace::default);
+    }
+
+    /// Executes one instruction, returning why to stop if a watched register changed or the
+    /// program halted.
+    pub fn step(&mut self) -> Result<Option<StopReason>, ComputerError> {
+        let Some(instruction) = self.computer.current_instruction()? else {
+            return Ok(Some(StopReason::Halted));
+        };
+        let instruction_pointer = self.computer.instruction_pointer();
+        let before = self.computer.registers();
+
+        let output = self.computer.step()?;
+        self.output.extend(output);
+
+        if let Some(trace) = &mut self.trace {
+            trace.entries.push(TraceEntry {
+                instruction_pointer,
+                instruction,
+                before,
+                after: self.computer.registers(),
+                output,
+            });
+        }
+
+        let changed_watch = self.watches.iter().find_map(|register| {
+            let old_value = match register {
+                Register::A => before.a,
+                Register::B => before.b,
+                Register::C => before.c,
+            };
+            let new_value = self.computer.register(*register);
+            (old_value != new_value).then_some(StopReason::Watch {
+                register: *register,
+                old_value,
+                new_value,
+            })
+        });
+        if changed_watch.is_some() {
+            return Ok(changed_watch);
+        }
+
+        Ok(self.computer.is_halted().then_some(StopReason::Halted))
+    }
+
+    /// Runs until a breakpoint is reached, a watched register changes or the program halts,
+    /// failing like [`Computer::run_program`] once the step limit of this call is used up or a
+    /// machine state repeats.
+    pub fn resume(&mut self) -> Result<StopReason, ComputerError> {
+        let mut loop_detector = LoopDetector::new(self.computer.step_limit());
+        loop {
+            if !self.computer.is_halted() {
+                loop_detector.observe(self.computer.state())?;
+            }
+            if let Some(stop_reason) = self.step()? {
+                return Ok(stop_reason);
+            }
+
+            let instruction_pointer = self.computer.instruction_pointer();
+            if self.breakpoints.contains(&instruction_pointer) {
+                return Ok(StopReason::Breakpoint(instruction_pointer));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MachineState;
+
+    fn example_computer() -> Computer {
+        let input_file_path = "test_input.txt";
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        crate::parse_input(&input_text).unwrap()
+    }
+
+    #[test]
+    fn test_breakpoints_and_watches() {
+        let mut debugger = Debugger::new(example_computer());
+        debugger.add_breakpoint(4);
+        assert_eq!(debugger.resume(), Ok(StopReason::Breakpoint(4)));
+        assert_eq!(debugger.output(), &[4]);
+
+        debugger.watch(Register::A);
+        assert_eq!(
+            debugger.resume(),
+            Ok(StopReason::Watch {
+                register: Register::A,
+                old_value: 364,
+                new_value: 182
+            })
+        );
+
+        assert!(debugger.remove_breakpoint(4));
+        while debugger.resume() != Ok(StopReason::Halted) {}
+        assert_eq!(debugger.output(), &[4, 6, 3, 5, 6, 3, 5, 2, 1, 0]);
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut debugger = Debugger::new(example_computer());
+        debugger.enable_trace();
+        assert_eq!(debugger.resume(), Ok(StopReason::Halted));
+
+        let trace = debugger.trace().unwrap();
+        assert_eq!(trace.entries.len(), 30);
+        assert_eq!(
+            trace.to_text().lines().take(2).collect::<Vec<&str>>(),
+            [
+                "  0: adv 1  a=729 b=0 c=0 -> a=364 b=0 c=0",
+                "  2: out a  a=364 b=0 c=0 -> a=364 b=0 c=0 out=4"
+            ]
+        );
+        assert!(trace.to_json().contains(r#""instruction": "jnz 0","#));
+    }
+
+    #[test]
+    fn test_resume_limits() {
+        let mut computer = example_computer();
+        computer.program = vec![1, 1, 3, 0];
+        let mut debugger = Debugger::new(computer.clone());
+        assert!(matches!(
+            debugger.resume(),
+            Err(ComputerError::InfiniteLoop(_))
+        ));
+
+        computer.program = vec![0, 1, 3, 0];
+        let registers = Registers {
+            a: 1 << 40,
+            b: 0,
+            c: 0,
+        };
+        let mut debugger = Debugger::new(computer.with_registers(registers).with_step_limit(5));
+        assert_eq!(
+            debugger.resume(),
+            Err(ComputerError::StepLimitExceeded(MachineState {
+                registers: Registers {
+                    a: 1 << 37,
+                    b: 0,
+                    c: 0
+                },
+                instruction_pointer: 2
+            }))
+        );
+    }
+}
@@ -0,0 +1,504 @@
+mod assembly;
+mod compiled;
+mod debugger;
+mod solver;
+
+pub use assembly::{assemble, decode, disassemble, Instruction, Opcode};
+pub use compiled::CompiledProgram;
+pub use debugger::{Debugger, Register, Registers, StopReason, Trace, TraceEntry};
+pub use solver::{analyze, find_register_a, LoopShape, SolverError};
+
+use aoc_2024_common::{
+    expect_prefix, expect_token, parse_number, Answer, ParseError, Solution, SolveError,
+    SolveResult,
+};
+use clap::Args;
+use std::ops::ControlFlow;
+
+/// Instruction budget of [`Computer::run_program`], the puzzle program needs a few hundred steps.
+pub const DEFAULT_STEP_LIMIT: u64 = 1_000_000;
+
+/// `value` divided by 2 to the power of `shift`, 0 once the shift is past all bits.
+fn shift_right(value: u64, shift: u64) -> u64 {
+    u32::try_from(shift)
+        .ok()
+        .and_then(|shift| value.checked_shr(shift))
+        .unwrap_or(0)
+}
+
+#[derive(Debug, Clone)]
+pub struct Computer {
+    register_a: u64,
+    register_b: u64,
+    register_c: u64,
+    program: Vec<u8>,
+    instruction_pointer: usize,
+    step_limit: u64,
+}
+
+/// Receives the numbers a program outputs while it runs.
+pub trait OutputSink {
+    /// Breaking stops the program early, e.g. as soon as the output is known to be wrong.
+    fn emit(&mut self, value: u8) -> ControlFlow<()>;
+}
+
+impl OutputSink for Vec<u8> {
+    fn emit(&mut self, value: u8) -> ControlFlow<()> {
+        self.push(value);
+        ControlFlow::Continue(())
+    }
+}
+
+/// Comma separated output, as the puzzle wants it.
+pub fn format_output(output: &[u8]) -> String {
+    output
+        .iter()
+        .map(|value| value.to_string())
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+/// Everything that decides how a run continues, besides the program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MachineState {
+    pub registers: Registers,
+    pub instruction_pointer: usize,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ComputerError {
+    InvalidOperand,
+    InvalidOpcode,
+    MissingOperand,
+    StepLimitExceeded(MachineState),
+    InfiniteLoop(MachineState),
+}
+
+/// Stops a run once it used up its step budget or a machine state repeats. Repetitions are
+/// found with Brent's algorithm, comparing against a single saved state that moves ahead at
+/// powers of two.
+#[derive(Debug, Clone)]
+pub(crate) struct LoopDetector {
+    step_limit: u64,
+    num_steps: u64,
+    saved_state: Option<MachineState>,
+    saved_state_age: u64,
+    saved_state_lifetime: u64,
+}
+
+impl LoopDetector {
+    pub(crate) fn new(step_limit: u64) -> Self {
+        Self {
+            step_limit,
+            num_steps: 0,
+            saved_state: None,
+            saved_state_age: 0,
+            saved_state_lifetime: 1,
+        }
+    }
+
+    /// Called with the state before every step.
+    #[inline]
+    pub(crate) fn observe(&mut self, state: MachineState) -> Result<(), ComputerError> {
+        match self.saved_state {
+            None => self.saved_state = Some(state),
+            Some(saved_state) if saved_state == state => {
+                return Err(ComputerError::InfiniteLoop(state));
+            }
+            Some(_) => {
+                self.saved_state_age += 1;
+                if self.saved_state_age == self.saved_state_lifetime {
+                    self.saved_state = Some(state);
+                    self.saved_state_age = 0;
+                    self.saved_state_lifetime *= 2;
+                }
+            }
+        }
+
+        if self.num_steps == self.step_limit {
+            return Err(ComputerError::StepLimitExceeded(state));
+        }
+        self.num_steps += 1;
+
+        Ok(())
+    }
+}
+
+impl Computer {
+    fn map_combo_operand(&self, operand: u8) -> Result<u64, ComputerError> {
+        match operand {
+            0..=3 => Ok(operand as u64),
+            4 => Ok(self.register_a),
+            5 => Ok(self.register_b),
+            6 => Ok(self.register_c),
+            _ => Err(ComputerError::InvalidOperand),
+        }
+    }
+
+    pub fn instruction_pointer(&self) -> usize {
+        self.instruction_pointer
+    }
+
+    pub fn register(&self, register: Register) -> u64 {
+        match register {
+            Register::A => self.register_a,
+            Register::B => self.register_b,
+            Register::C => self.register_c,
+        }
+    }
+
+    pub fn registers(&self) -> Registers {
+        Registers {
+            a: self.register_a,
+            b: self.register_b,
+            c: self.register_c,
+        }
+    }
+
+    pub fn state(&self) -> MachineState {
+        MachineState {
+            registers: self.registers(),
+            instruction_pointer: self.instruction_pointer,
+        }
+    }
+
+    pub fn with_step_limit(mut self, step_limit: u64) -> Self {
+        self.step_limit = step_limit;
+        self
+    }
+
+    pub fn with_registers(mut self, registers: Registers) -> Self {
+        self.register_a = registers.a;
+        self.register_b = registers.b;
+        self.register_c = registers.c;
+        self
+    }
+
+    pub fn step_limit(&self) -> u64 {
+        self.step_limit
+    }
+
+    pub fn compile(&self) -> CompiledProgram {
+        CompiledProgram::compile(&self.program)
+    }
+
+    pub fn is_halted(&self) -> bool {
+        (self.instruction_pointer + 1) >= self.program.len()
+    }
+
+    /// Instruction at the instruction pointer, `None` once halted.
+    pub fn current_instruction(&self) -> Result<Option<Instruction>, ComputerError> {
+        if self.is_halted() {
+            return Ok(None);
+        }
+
+        let opcode = Opcode::from_byte(self.program[self.instruction_pointer])
+            .ok_or(ComputerError::InvalidOpcode)?;
+        let operand = self.program[self.instruction_pointer + 1];
+        Ok(Some(Instruction { opcode, operand }))
+    }
+
+    /// Executes the instruction at the instruction pointer and returns its output, if any.
+    pub fn step(&mut self) -> Result<Option<u8>, ComputerError> {
+        if self.is_halted() {
+            return Ok(None);
+        }
+
+        let opcode = self.program[self.instruction_pointer];
+        let operand = self.program[self.instruction_pointer + 1];
+        let mut output = None;
+
+        match opcode {
+            0 => {
+                self.register_a = shift_right(self.register_a, self.map_combo_operand(operand)?);
+                self.instruction_pointer += 2;
+            }
+            1 => {
+                self.register_b ^= operand as u64;
+                self.instruction_pointer += 2;
+            }
+            2 => {
+                self.register_b = self.map_combo_operand(operand)? & 7;
+                self.instruction_pointer += 2;
+            }
+            3 => {
+                if self.register_a != 0 {
+                    self.instruction_pointer = operand as usize
+                } else {
+                    self.instruction_pointer += 2;
+                }
+            }
+            4 => {
+                self.register_b ^= self.register_c;
+                self.instruction_pointer += 2;
+            }
+            5 => {
+                output = Some((self.map_combo_operand(operand)? & 7) as u8);
+                self.instruction_pointer += 2;
+            }
+            6 => {
+                self.register_b = shift_right(self.register_a, self.map_combo_operand(operand)?);
+                self.instruction_pointer += 2;
+            }
+            7 => {
+                self.register_c = shift_right(self.register_a, self.map_combo_operand(operand)?);
+                self.instruction_pointer += 2;
+            }
+            _ => return Err(ComputerError::InvalidOpcode),
+        }
+
+        Ok(output)
+    }
+
+    pub fn run_program(&mut self) -> Result<Vec<u8>, ComputerError> {
+        let mut output = Vec::new();
+        self.run_with_sink(&mut output)?;
+        Ok(output)
+    }
+
+    /// Runs until the program halts or `sink` breaks, failing once the step limit is used up or
+    /// a machine state repeats, see [`LoopDetector`].
+    pub fn run_with_sink(&mut self, sink: &mut impl OutputSink) -> Result<(), ComputerError> {
+        let mut loop_detector = LoopDetector::new(self.step_limit);
+
+        while !self.is_halted() {
+            loop_detector.observe(self.state())?;
+
+            let output = self.step()?;
+            if let Some(ControlFlow::Break(())) = output.map(|value| sink.emit(value)) {
+                return Ok(());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_input(input_text: &str) -> Result<Computer, ParseError> {
+    let mut lines = input_text.lines().filter(|line| !line.is_empty());
+    let mut next_line = |prefix: &str| {
+        let line = expect_token(input_text, input_text, lines.next(), &format!("`{prefix}`"))?;
+        expect_prefix(input_text, line, prefix)
+    };
+
+    let register_a = parse_number(input_text, next_line("Register A: ")?)?;
+    let register_b = parse_number(input_text, next_line("Register B: ")?)?;
+    let register_c = parse_number(input_text, next_line("Register C: ")?)?;
+
+    let program_text = next_line("Program: ")?;
+    let program: Vec<u8> = program_text
+        .split(',')
+        .map(|number| {
+            parse_number::<u8>(input_text, number)
+                .ok()
+                .filter(|number| *number <= 7)
+                .ok_or_else(|| ParseError::at(input_text, number, "3-bit number"))
+        })
+        .collect::<Result<_, ParseError>>()?;
+    if !program.len().is_multiple_of(2) {
+        return Err(ParseError::after(
+            input_text,
+            program_text,
+            "operand of the last instruction",
+        ));
+    }
+
+    Ok(Computer {
+        register_a,
+        register_b,
+        register_c,
+        program,
+        instruction_pointer: 0,
+        step_limit: DEFAULT_STEP_LIMIT,
+    })
+}
+
+fn process_part1(mut computer: Computer) -> Result<Vec<u8>, ComputerError> {
+    computer.run_program()
+}
+
+/*
+puzzle program, as rendered by `disassemble`:
+bst a  ; b = a & 7
+bxl 2  ; b = b ^ 2
+cdv b  ; c = a >> b
+bxc 7  ; b = b ^ c
+bxl 3  ; b = b ^ 3
+out b  ; out(b & 7)
+adv 3  ; a = a >> 3
+jnz 0  ; jump to 0 if a != 0
+ */
+fn process_part2(computer: Computer) -> Result<u64, SolverError> {
+    let program = computer.program.clone();
+    find_register_a(&computer, &program)
+}
+
+/// Overrides of the registers and the program read from the input.
+#[derive(Debug, Clone, Args)]
+pub struct Day17Parameters {
+    /// Initial value of register A
+    #[arg(long)]
+    register_a: Option<u64>,
+
+    /// Initial value of register B
+    #[arg(long)]
+    register_b: Option<u64>,
+
+    /// Initial value of register C
+    #[arg(long)]
+    register_c: Option<u64>,
+
+    /// Comma separated program
+    #[arg(long, value_delimiter = ',', value_parser = clap::value_parser!(u8).range(0..=7))]
+    program: Option<Vec<u8>>,
+
+    /// Maximum number of instructions executed per run
+    #[arg(long, default_value_t = DEFAULT_STEP_LIMIT)]
+    step_limit: u64,
+}
+
+impl Day17Parameters {
+    fn apply(&self, computer: &Computer) -> Computer {
+        let mut computer = computer.clone().with_step_limit(self.step_limit);
+        computer.register_a = self.register_a.unwrap_or(computer.register_a);
+        computer.register_b = self.register_b.unwrap_or(computer.register_b);
+        computer.register_c = self.register_c.unwrap_or(computer.register_c);
+        if let Some(program) = &self.program {
+            computer.program = program.clone();
+        }
+
+        computer
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Input = Computer;
+
+    type Parameters = Day17Parameters;
+
+    fn parse_input(input_text: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input_text)
+    }
+
+    fn process_part1(input: &Self::Input, parameters: &Self::Parameters) -> SolveResult {
+        process_part1(parameters.apply(input))
+            .map(|output| Answer::from(format_output(&output)))
+            .map_err(|error| SolveError::Failed(format!("{error:?}")))
+    }
+
+    fn process_part2(input: &Self::Input, parameters: &Self::Parameters) -> SolveResult {
+        process_part2(parameters.apply(input))
+            .map(Answer::from)
+            .map_err(|error| match error {
+                SolverError::UnsupportedShape(reason) => SolveError::Failed(reason),
+                SolverError::NoSolution => SolveError::NoAnswer,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        let input_file_path = "test_input.txt";
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        let computer = parse_input(&input_text).unwrap();
+        assert_eq!(computer.register_a, 729);
+        assert_eq!(computer.program, vec![0, 1, 5, 4, 3, 0]);
+    }
+
+    #[test]
+    fn test_parse_input_errors() {
+        let input_text = "Register A: 729\nRegister C: 0\n\nProgram: 0,1,5,4,3,0\n";
+        let error = parse_input(input_text).unwrap_err();
+        assert_eq!(error, ParseError::new(2, 1, "`Register B: `"));
+
+        let input_text = "Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,8,4\n";
+        let error = parse_input(input_text).unwrap_err();
+        assert_eq!(error, ParseError::new(5, 14, "3-bit number"));
+
+        let input_text = "Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5\n";
+        let error = parse_input(input_text).unwrap_err();
+        assert_eq!(
+            error,
+            ParseError::new(5, 15, "operand of the last instruction")
+        );
+    }
+
+    #[test]
+    fn test_process_part1() {
+        let computer = Computer {
+            register_a: 729,
+            register_b: 0,
+            register_c: 0,
+            program: vec![0, 1, 5, 4, 3, 0],
+            instruction_pointer: 0,
+            step_limit: DEFAULT_STEP_LIMIT,
+        };
+        let result_part1 = process_part1(computer);
+        assert_eq!(result_part1, Ok(vec![4, 6, 3, 5, 6, 3, 5, 2, 1, 0]));
+        assert_eq!(format_output(&result_part1.unwrap()), "4,6,3,5,6,3,5,2,1,0");
+    }
+
+    #[test]
+    fn test_run_program_limits() {
+        let looping_computer = Computer {
+            register_a: 1,
+            register_b: 0,
+            register_c: 0,
+            program: vec![1, 3, 3, 0],
+            instruction_pointer: 0,
+            step_limit: DEFAULT_STEP_LIMIT,
+        };
+        let state = MachineState {
+            registers: Registers { a: 1, b: 0, c: 0 },
+            instruction_pointer: 2,
+        };
+        assert_eq!(
+            looping_computer.clone().run_program(),
+            Err(ComputerError::InfiniteLoop(state))
+        );
+
+        let mut counting_computer = looping_computer.with_step_limit(5);
+        counting_computer.program = vec![0, 1, 3, 0];
+        counting_computer.register_a = 1 << 40;
+        let state = MachineState {
+            registers: Registers {
+                a: 1 << 37,
+                b: 0,
+                c: 0,
+            },
+            instruction_pointer: 2,
+        };
+        assert_eq!(
+            counting_computer.run_program(),
+            Err(ComputerError::StepLimitExceeded(state))
+        );
+    }
+
+    #[test]
+    fn test_run_with_sink_stops_early() {
+        struct FirstValue(Option<u8>);
+
+        impl OutputSink for FirstValue {
+            fn emit(&mut self, value: u8) -> ControlFlow<()> {
+                self.0 = Some(value);
+                ControlFlow::Break(())
+            }
+        }
+
+        let input_file_path = "test_input.txt";
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        let mut computer = parse_input(&input_text).unwrap();
+        let mut first_value = FirstValue(None);
+        assert_eq!(computer.run_with_sink(&mut first_value), Ok(()));
+        assert_eq!(first_value.0, Some(4));
+        assert_eq!(computer.instruction_pointer(), 4);
+    }
+}
@@ -0,0 +1,271 @@
+use std::ops::ControlFlow;
+
+use crate::{decode, CompiledProgram, Computer, Instruction, Opcode, OutputSink, Registers};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolverError {
+    /// The program is not a loop the search below can handle, with the reason.
+    UnsupportedShape(String),
+    NoSolution,
+}
+
+/// Loop structure the solver relies on: every iteration shifts register A right by `shift`
+/// bits, emits `outputs_per_iteration` numbers and computes B and C from A alone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoopShape {
+    pub shift: u32,
+    pub outputs_per_iteration: usize,
+}
+
+fn unsupported(reason: &str) -> SolverError {
+    SolverError::UnsupportedShape(reason.to_string())
+}
+
+/// Registers B and C read and written by `instruction`, as `(reads, writes)`.
+fn register_accesses(instruction: &Instruction) -> (Vec<char>, Vec<char>) {
+    let mut reads = Vec::new();
+    let combo_operand_register = match instruction.operand {
+        5 => Some('b'),
+        6 => Some('c'),
+        _ => None,
+    };
+
+    let writes = match instruction.opcode {
+        Opcode::Adv | Opcode::Out => {
+            reads.extend(combo_operand_register);
+            vec![]
+        }
+        Opcode::Bxl => {
+            reads.push('b');
+            vec!['b']
+        }
+        Opcode::Bst | Opcode::Bdv => {
+            reads.extend(combo_operand_register);
+            vec!['b']
+        }
+        Opcode::Cdv => {
+            reads.extend(combo_operand_register);
+            vec!['c']
+        }
+        Opcode::Bxc => {
+            reads.extend(['b', 'c']);
+            vec!['b']
+        }
+        Opcode::Jnz => vec![],
+    };
+
+    (reads, writes)
+}
+
+pub fn analyze(program: &[u8]) -> Result<LoopShape, SolverError> {
+    let instructions = decode(program)
+        .map_err(|error| SolverError::UnsupportedShape(format!("invalid program ({error:?})")))?;
+    let Some((last_instruction, body)) = instructions.split_last() else {
+        return Err(unsupported("program is empty"));
+    };
+    if *last_instruction
+        != (Instruction {
+            opcode: Opcode::Jnz,
+            operand: 0,
+        })
+    {
+        return Err(unsupported("program has to end with `jnz 0`"));
+    }
+    if body
+        .iter()
+        .any(|instruction| instruction.opcode == Opcode::Jnz)
+    {
+        return Err(unsupported("only the last instruction may jump"));
+    }
+
+    let shifts: Vec<&Instruction> = body
+        .iter()
+        .filter(|instruction| instruction.opcode == Opcode::Adv)
+        .collect();
+    let shift = match shifts[..] {
+        [shift] if (1..=3).contains(&shift.operand) => shift.operand as u32,
+        _ => {
+            return Err(unsupported(
+                "register a has to be shifted by a constant 1 to 3 exactly once per iteration",
+            ))
+        }
+    };
+
+    let outputs_per_iteration = body
+        .iter()
+        .filter(|instruction| instruction.opcode == Opcode::Out)
+        .count();
+    if outputs_per_iteration == 0 {
+        return Err(unsupported("program has no `out` instruction"));
+    }
+
+    let mut written_registers = Vec::new();
+    for instruction in body {
+        let (reads, writes) = register_accesses(instruction);
+        if let Some(register) = reads
+            .iter()
+            .find(|register| !written_registers.contains(*register))
+        {
+            return Err(SolverError::UnsupportedShape(format!(
+                "register {register} is carried over between iterations"
+            )));
+        }
+        written_registers.extend(writes);
+    }
+
+    Ok(LoopShape {
+        shift,
+        outputs_per_iteration,
+    })
+}
+
+/// Compares the output with `expected` as it is produced, stopping at the first difference.
+struct ExpectedOutput<'a> {
+    expected: &'a [u8],
+    num_matching: usize,
+    is_different: bool,
+}
+
+impl<'a> ExpectedOutput<'a> {
+    fn new(expected: &'a [u8]) -> Self {
+        Self {
+            expected,
+            num_matching: 0,
+            is_different: false,
+        }
+    }
+
+    fn is_match(&self) -> bool {
+        !self.is_different && (self.num_matching == self.expected.len())
+    }
+}
+
+impl OutputSink for ExpectedOutput<'_> {
+    fn emit(&mut self, value: u8) -> ControlFlow<()> {
+        if self.expected.get(self.num_matching) == Some(&value) {
+            self.num_matching += 1;
+            ControlFlow::Continue(())
+        } else {
+            self.is_different = true;
+            ControlFlow::Break(())
+        }
+    }
+}
+
+fn registers_with_a(computer: &Computer, register_a: u64) -> Registers {
+    Registers {
+        a: register_a,
+        ..computer.registers()
+    }
+}
+
+fn outputs_expected(
+    compiled_program: &CompiledProgram,
+    computer: &Computer,
+    register_a: u64,
+    expected: &[u8],
+) -> bool {
+    let mut expected_output = ExpectedOutput::new(expected);
+    let registers = registers_with_a(computer, register_a);
+    compiled_program
+        .run_with_sink(registers, computer.step_limit, &mut expected_output)
+        .is_ok()
+        && expected_output.is_match()
+}
+
+/// Finds the smallest initial register A for which `computer` outputs `target`.
+///
+/// The last iteration only sees the highest bits of A, so A is built from its highest `shift`
+/// bits downwards, keeping the candidates whose run ends with the matching suffix of `target`.
+/// Exploring the smaller candidates first makes the first complete match the smallest one.
+pub fn find_register_a(computer: &Computer, target: &[u8]) -> Result<u64, SolverError> {
+    let shape = analyze(&computer.program)?;
+    if target.is_empty() || !target.len().is_multiple_of(shape.outputs_per_iteration) {
+        return Err(SolverError::NoSolution);
+    }
+
+    let num_iterations = target.len() / shape.outputs_per_iteration;
+
+    let compiled_program = computer.compile();
+    let chunk_values = 0..(1u64 << shape.shift);
+    let mut stack: Vec<(usize, u64)> = vec![(0, 0)];
+    while let Some((num_known_iterations, known_bits)) = stack.pop() {
+        if known_bits > (u64::MAX >> shape.shift) {
+            continue;
+        }
+
+        let target_suffix =
+            &target[target.len() - (num_known_iterations + 1) * shape.outputs_per_iteration..];
+        let mut matching_candidates = Vec::new();
+        for chunk_value in chunk_values.clone() {
+            let candidate = (known_bits << shape.shift) + chunk_value;
+            if outputs_expected(&compiled_program, computer, candidate, target_suffix) {
+                if (num_known_iterations + 1) == num_iterations {
+                    return Ok(candidate);
+                }
+                matching_candidates.push((num_known_iterations + 1, candidate));
+            }
+        }
+
+        stack.extend(matching_candidates.into_iter().rev());
+    }
+
+    Err(SolverError::NoSolution)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assemble;
+
+    fn computer_with_program(source: &str) -> Computer {
+        let input_file_path = "test_input.txt";
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        let mut computer = crate::parse_input(&input_text).unwrap();
+        computer.program = assemble(source).unwrap();
+        computer
+    }
+
+    #[test]
+    fn test_find_register_a() {
+        let computer = computer_with_program("adv 3\nout a\njnz 0");
+        assert_eq!(find_register_a(&computer, &[0, 3, 5, 4, 3, 0]), Ok(117440));
+
+        let computer = computer_with_program("bst a\nout b\nbdv 1\nout b\nadv 2\njnz 0");
+        let compiled_program = computer.compile();
+        let run = |register_a| {
+            compiled_program
+                .run(registers_with_a(&computer, register_a), computer.step_limit)
+                .unwrap()
+        };
+        let target = run(1234);
+        let register_a = find_register_a(&computer, &target).unwrap();
+        assert!(register_a <= 1234);
+        assert_eq!(run(register_a), target);
+        assert_eq!(
+            find_register_a(&computer, &[1, 0, 3]),
+            Err(SolverError::NoSolution)
+        );
+    }
+
+    #[test]
+    fn test_analyze_unsupported_shapes() {
+        let shape_error = |source: &str| match analyze(&assemble(source).unwrap()) {
+            Err(SolverError::UnsupportedShape(reason)) => reason,
+            result => panic!("unexpected {result:?}"),
+        };
+
+        assert_eq!(
+            shape_error("bxl 3\nout b\nadv 3\njnz 0"),
+            "register b is carried over between iterations"
+        );
+        assert_eq!(
+            shape_error("out a\njnz 0"),
+            "register a has to be shifted by a constant 1 to 3 exactly once per iteration"
+        );
+        assert_eq!(
+            shape_error("adv 1\nout a"),
+            "program has to end with `jnz 0`"
+        );
+    }
+}
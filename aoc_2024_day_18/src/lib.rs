@@ -0,0 +1,197 @@
+use aoc_2024_common::{
+    expect_split_once, parse_number, Answer, ParseError, Solution, SolveError, SolveResult,
+};
+use aoc_2024_grid::{Grid, Point};
+use clap::{builder::RangedU64ValueParser, Args};
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashSet},
+};
+
+const SAFE: char = '.';
+const CORRUPTED: char = '#';
+
+fn parse_input(input_text: &str) -> Result<Vec<Point>, ParseError> {
+    input_text
+        .lines()
+        .map(|line| {
+            let (column, row) = expect_split_once(input_text, line, ",")?;
+            let column = parse_number::<usize>(input_text, column)? + 1;
+            let row = parse_number::<usize>(input_text, row)? + 1;
+            Ok(Point::new(row, column))
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Node {
+    location: Point,
+    steps: u32,
+    min_required_steps: usize,
+}
+
+impl Node {
+    pub fn new(location: Point, steps: u32, end: Point) -> Self {
+        Self {
+            location,
+            steps,
+            min_required_steps: steps as usize + location.manhattan_distance(&end),
+        }
+    }
+}
+
+impl Ord for Node {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.min_required_steps.cmp(&self.min_required_steps)
+    }
+}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn process_part1(bytes: &[Point], memory_size: usize, bytes_to_take: usize) -> Option<u32> {
+    let start = Point::new(1, 1);
+    let end = Point::new(memory_size, memory_size);
+    let mut memory_space = Grid::new(memory_size, memory_size, SAFE).padded(1, CORRUPTED);
+
+    bytes
+        .iter()
+        .take(bytes_to_take)
+        .for_each(|byte| memory_space[*byte] = CORRUPTED);
+
+    let mut priority_queue: BinaryHeap<Node> = BinaryHeap::from(vec![Node::new(start, 0, end)]);
+    let mut visited: HashSet<Point> = HashSet::new();
+    while let Some(node) = priority_queue.pop() {
+        if node.location == end {
+            return Some(node.steps);
+        }
+        visited.insert(node.location);
+
+        for neighbor_location in memory_space.neighbors4(node.location) {
+            if visited.contains(&neighbor_location)
+                || (memory_space[neighbor_location] == CORRUPTED)
+            {
+                continue;
+            }
+
+            priority_queue.push(Node::new(neighbor_location, node.steps + 1, end));
+        }
+    }
+
+    None
+}
+
+/// Fails for the first byte that falls outside of the memory space.
+fn check_bytes(bytes: &[Point], memory_size: usize) -> Result<(), SolveError> {
+    match bytes
+        .iter()
+        .find(|byte| byte.row > memory_size || byte.column > memory_size)
+    {
+        Some(byte) => Err(SolveError::Failed(format!(
+            "byte {},{} is outside of the memory space",
+            byte.column - 1,
+            byte.row - 1
+        ))),
+        None => Ok(()),
+    }
+}
+
+fn process_part2(bytes: &[Point], memory_size: usize) -> String {
+    let mut left: usize = 0;
+    let mut right: usize = bytes.len() - 1;
+
+    while left < right {
+        let mid = (left + right) / 2;
+        let mid_result = process_part1(bytes, memory_size, mid);
+
+        if mid_result.is_none() {
+            right = mid - 1;
+        } else {
+            left = mid + 1;
+        }
+    }
+
+    let result_byte = bytes[left];
+    format!("{},{}", result_byte.column - 1, result_byte.row - 1)
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct Day18Parameters {
+    /// Width and height of the memory space
+    #[arg(
+        long,
+        default_value_t = 71,
+        value_parser = RangedU64ValueParser::<usize>::new().range(1..)
+    )]
+    memory_size: usize,
+
+    /// Number of fallen bytes in part 1
+    #[arg(long, default_value_t = 1024)]
+    bytes_to_take: usize,
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Input = Vec<Point>;
+
+    type Parameters = Day18Parameters;
+
+    fn parse_input(input_text: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input_text)
+    }
+
+    fn process_part1(input: &Self::Input, parameters: &Self::Parameters) -> SolveResult {
+        check_bytes(input, parameters.memory_size)?;
+        process_part1(input, parameters.memory_size, parameters.bytes_to_take)
+            .map(Answer::from)
+            .ok_or(SolveError::NoAnswer)
+    }
+
+    fn process_part2(input: &Self::Input, parameters: &Self::Parameters) -> SolveResult {
+        check_bytes(input, parameters.memory_size)?;
+        Ok(process_part2(input, parameters.memory_size).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process_part1() {
+        let input_file_path = "test_input.txt";
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        let input = parse_input(&input_text).unwrap();
+        let result_part1 = process_part1(&input, 7, 12);
+        assert_eq!(result_part1, Some(22));
+    }
+
+    #[test]
+    fn test_process_part2() {
+        let input_file_path = "test_input.txt";
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        let input = parse_input(&input_text).unwrap();
+        let result_part2 = process_part2(&input, 7);
+        assert_eq!(result_part2, "6,1");
+    }
+
+    #[test]
+    fn test_check_bytes() {
+        let input_file_path = "test_input.txt";
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        let input = parse_input(&input_text).unwrap();
+        assert_eq!(check_bytes(&input, 7), Ok(()));
+        assert_eq!(
+            check_bytes(&input, 6),
+            Err(SolveError::Failed(
+                "byte 6,3 is outside of the memory space".to_string()
+            ))
+        );
+    }
+}
@@ -0,0 +1,131 @@
+use aoc_2024_common::{expect_split_once, NoParameters, ParseError, Solution, SolveResult};
+use std::collections::HashSet;
+
+pub struct OnsenBranding {
+    towels: HashSet<String>,
+    designs: Vec<String>,
+}
+
+fn parse_input(input_text: &str) -> Result<OnsenBranding, ParseError> {
+    let (towels_text, designs_text) = expect_split_once(input_text, input_text, "\n\n")?;
+
+    let towels: HashSet<String> = towels_text
+        .lines()
+        .flat_map(|line| line.split(", "))
+        .map(|towel| {
+            if towel.is_empty() {
+                Err(ParseError::at(input_text, towel, "towel pattern"))
+            } else {
+                Ok(towel.to_string())
+            }
+        })
+        .collect::<Result<_, ParseError>>()?;
+
+    let designs: Vec<String> = designs_text
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .map(|line| line.to_string())
+        .collect();
+
+    Ok(OnsenBranding { towels, designs })
+}
+
+fn process_part1(onsen_branding: &OnsenBranding) -> u32 {
+    let mut count: u32 = 0;
+
+    'design_loop: for design in &onsen_branding.designs {
+        let mut is_start = vec![false; design.len() + 1];
+        is_start[0] = true;
+
+        for start in 0..design.len() {
+            if !is_start[start] {
+                continue;
+            }
+
+            for end in start..design.len() {
+                if onsen_branding.towels.contains(&design[start..=end]) {
+                    is_start[end + 1] = true;
+
+                    if (end + 1) == design.len() {
+                        count += 1;
+                        continue 'design_loop;
+                    }
+                }
+            }
+        }
+    }
+
+    count
+}
+
+fn process_part2(onsen_branding: &OnsenBranding) -> u64 {
+    let mut count: u64 = 0;
+
+    for design in &onsen_branding.designs {
+        let mut num_ways = vec![0u64; design.len() + 1];
+        num_ways[0] = 1;
+
+        for start in 0..design.len() {
+            if num_ways[start] == 0 {
+                continue;
+            }
+
+            for end in start..design.len() {
+                if onsen_branding.towels.contains(&design[start..=end]) {
+                    num_ways[end + 1] += num_ways[start];
+                }
+            }
+        }
+
+        count += num_ways.last().unwrap();
+    }
+
+    count
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+
+    type Input = OnsenBranding;
+
+    type Parameters = NoParameters;
+
+    fn parse_input(input_text: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input_text)
+    }
+
+    fn process_part1(input: &Self::Input, _parameters: &Self::Parameters) -> SolveResult {
+        Ok(process_part1(input).into())
+    }
+
+    fn process_part2(input: &Self::Input, _parameters: &Self::Parameters) -> SolveResult {
+        Ok(process_part2(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process_part1() {
+        let input_file_path = "test_input.txt";
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        let input = parse_input(&input_text).unwrap();
+
+        let result_part1 = process_part1(&input);
+        assert_eq!(result_part1, 6);
+    }
+
+    #[test]
+    fn test_process_part2() {
+        let input_file_path = "test_input.txt";
+        let input_text = std::fs::read_to_string(input_file_path).unwrap();
+        let input = parse_input(&input_text).unwrap();
+
+        let result_part2 = process_part2(&input);
+        assert_eq!(result_part2, 16);
+    }
+}
@@ -0,0 +1,97 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    /// The 4-connected directions in clockwise order, starting with `Up`.
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// The 8-connected directions in clockwise order, starting with `Up`.
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    /// Row and column delta of a single step.
+    pub fn delta(&self) -> (isize, isize) {
+        match self {
+            Direction::Up => (-1, 0),
+            Direction::UpRight => (-1, 1),
+            Direction::Right => (0, 1),
+            Direction::DownRight => (1, 1),
+            Direction::Down => (1, 0),
+            Direction::DownLeft => (1, -1),
+            Direction::Left => (0, -1),
+            Direction::UpLeft => (-1, -1),
+        }
+    }
+
+    pub fn is_cardinal(&self) -> bool {
+        matches!(
+            self,
+            Direction::Up | Direction::Right | Direction::Down | Direction::Left
+        )
+    }
+
+    fn rotate(&self, eighth_turns: usize) -> Direction {
+        let index = Direction::ALL
+            .iter()
+            .position(|direction| direction == self)
+            .unwrap();
+        Direction::ALL[(index + eighth_turns) % Direction::ALL.len()]
+    }
+
+    /// Rotates by 90 degrees clockwise.
+    pub fn turn_right(&self) -> Direction {
+        self.rotate(2)
+    }
+
+    /// Rotates by 90 degrees counterclockwise.
+    pub fn turn_left(&self) -> Direction {
+        self.rotate(6)
+    }
+
+    pub fn opposite(&self) -> Direction {
+        self.rotate(4)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turn_right() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Left.turn_right(), Direction::Up);
+        assert_eq!(Direction::UpLeft.turn_right(), Direction::UpRight);
+        assert_eq!(Direction::Down.turn_left(), Direction::Right);
+    }
+
+    #[test]
+    fn test_opposite() {
+        for direction in Direction::ALL {
+            let (row_delta, column_delta) = direction.delta();
+            assert_eq!(direction.opposite().delta(), (-row_delta, -column_delta));
+        }
+    }
+}
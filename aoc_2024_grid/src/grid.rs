@@ -0,0 +1,381 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use aoc_2024_common::ParseError;
+
+use crate::{Direction, Point};
+
+/// Rectangular grid stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    num_rows: usize,
+    num_columns: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(num_rows: usize, num_columns: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            num_rows,
+            num_columns,
+            cells: vec![value; num_rows * num_columns],
+        }
+    }
+
+    /// Panics if the rows are not all of the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let num_rows = rows.len();
+        let num_columns = rows.first().map_or(0, |row| row.len());
+        assert!(
+            rows.iter().all(|row| row.len() == num_columns),
+            "rows of different length"
+        );
+
+        Self {
+            num_rows,
+            num_columns,
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    /// Builds a grid from the lines of `text`, mapping every character with `parse_cell`.
+    pub fn parse(text: &str, mut parse_cell: impl FnMut(char) -> T) -> Self {
+        Self::from_rows(
+            text.lines()
+                .map(|line| line.chars().map(&mut parse_cell).collect())
+                .collect(),
+        )
+    }
+
+    /// Like [`Grid::parse`], but `parse_cell` may reject characters, in which case the error
+    /// names `expected`. Rows of different length are rejected as well.
+    pub fn try_parse(
+        text: &str,
+        expected: &str,
+        mut parse_cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut rows: Vec<Vec<T>> = Vec::new();
+        for (row_index, line) in text.lines().enumerate() {
+            let row: Vec<T> = line
+                .chars()
+                .enumerate()
+                .map(|(column_index, ch)| {
+                    parse_cell(ch).ok_or(ParseError::new(row_index + 1, column_index + 1, expected))
+                })
+                .collect::<Result<_, _>>()?;
+
+            if let Some(first_row) = rows.first() {
+                if row.len() != first_row.len() {
+                    let column = row.len().min(first_row.len()) + 1;
+                    return Err(ParseError::new(
+                        row_index + 1,
+                        column,
+                        format!("row of {} cells", first_row.len()),
+                    ));
+                }
+            }
+            rows.push(row);
+        }
+
+        Ok(Self::from_rows(rows))
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    pub fn num_columns(&self) -> usize {
+        self.num_columns
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        (point.row < self.num_rows) && (point.column < self.num_columns)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        if self.contains(point) {
+            Some(&self.cells[point.row * self.num_columns + point.column])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        if self.contains(point) {
+            Some(&mut self.cells[point.row * self.num_columns + point.column])
+        } else {
+            None
+        }
+    }
+
+    /// Swaps the values at two points, panics if either lies outside the grid.
+    pub fn swap(&mut self, point_1: Point, point_2: Point) {
+        assert!(
+            self.contains(point_1) && self.contains(point_2),
+            "point outside of grid"
+        );
+        self.cells.swap(
+            point_1.row * self.num_columns + point_1.column,
+            point_2.row * self.num_columns + point_2.column,
+        );
+    }
+
+    /// All points in row-major order.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let num_columns = self.num_columns;
+        (0..self.num_rows)
+            .flat_map(move |row| (0..num_columns).map(move |column| Point::new(row, column)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// First point in row-major order whose value satisfies `predicate`.
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find(|(_, value)| predicate(value))
+            .map(|(point, _)| point)
+    }
+
+    /// The neighbouring point in `direction`, `None` if it lies outside the grid.
+    pub fn neighbor(&self, point: Point, direction: Direction) -> Option<Point> {
+        point
+            .step(direction)
+            .filter(|neighbor| self.contains(*neighbor))
+    }
+
+    /// The 4-connected neighbours inside the grid.
+    pub fn neighbors4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::CARDINAL
+            .into_iter()
+            .filter_map(move |direction| self.neighbor(point, direction))
+    }
+
+    /// The 8-connected neighbours inside the grid.
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.neighbor(point, direction))
+    }
+
+    /// Points from `start` (inclusive) in `direction` until the edge of the grid.
+    pub fn ray(&self, start: Point, direction: Direction) -> impl Iterator<Item = Point> + '_ {
+        std::iter::successors(
+            Some(start).filter(|start| self.contains(*start)),
+            move |point| self.neighbor(*point, direction),
+        )
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[(row * self.num_columns)..((row + 1) * self.num_columns)]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.num_rows).map(|row| self.row(row))
+    }
+
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        self.cells
+            .iter()
+            .skip(column)
+            .step_by(self.num_columns.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.num_columns).map(|column| self.column(column))
+    }
+
+    /// Diagonals running `\`, starting with the bottom-left corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.num_rows)
+            .rev()
+            .map(|row| Point::new(row, 0))
+            .chain((1..self.num_columns).map(|column| Point::new(0, column)));
+        starts.map(|start| {
+            self.ray(start, Direction::DownRight)
+                .map(|point| &self[point])
+        })
+    }
+
+    /// Diagonals running `/`, starting with the top-left corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.num_rows).map(|row| Point::new(row, 0)).chain(
+            (1..self.num_columns).map(|column| Point::new(self.num_rows.saturating_sub(1), column)),
+        );
+        starts.map(|start| {
+            self.ray(start, Direction::UpRight)
+                .map(|point| &self[point])
+        })
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            num_rows: self.num_rows,
+            num_columns: self.num_columns,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Surrounds the grid with `padding` rows and columns of `value` on every side.
+    pub fn padded(&self, padding: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let mut padded = Grid::new(
+            self.num_rows + 2 * padding,
+            self.num_columns + 2 * padding,
+            value,
+        );
+        for (point, value) in self.iter() {
+            padded[Point::new(point.row + padding, point.column + padding)] = value.clone();
+        }
+
+        padded
+    }
+
+    pub fn transposed(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_rows(
+            self.columns()
+                .map(|column| column.cloned().collect())
+                .collect(),
+        )
+    }
+
+    pub fn rotated_clockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_rows(
+            self.columns()
+                .map(|column| {
+                    let mut row: Vec<T> = column.cloned().collect();
+                    row.reverse();
+                    row
+                })
+                .collect(),
+        )
+    }
+
+    pub fn rotated_counterclockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let mut rows: Vec<Vec<T>> = self
+            .columns()
+            .map(|column| column.cloned().collect())
+            .collect();
+        rows.reverse();
+        Grid::from_rows(rows)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &Self::Output {
+        self.get(point).expect("point outside of grid")
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        self.get_mut(point).expect("point outside of grid")
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for value in row {
+                write!(f, "{value}")?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_grid() -> Grid<char> {
+        Grid::parse("abc\ndef\n", |ch| ch)
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = test_grid();
+        assert_eq!(grid.num_rows(), 2);
+        assert_eq!(grid.num_columns(), 3);
+        assert_eq!(grid[Point::new(1, 2)], 'f');
+        assert_eq!(grid.get(Point::new(2, 0)), None);
+        assert_eq!(grid.find(|ch| *ch == 'e'), Some(Point::new(1, 1)));
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+    }
+
+    #[test]
+    fn test_try_parse() {
+        let grid = Grid::try_parse("12\n34\n", "digit", |ch| ch.to_digit(10));
+        assert_eq!(grid.unwrap()[Point::new(1, 0)], 3);
+
+        let error = Grid::try_parse("12\n3x\n", "digit", |ch| ch.to_digit(10)).unwrap_err();
+        assert_eq!(error, ParseError::new(2, 2, "digit"));
+
+        let error = Grid::try_parse("12\n345\n", "digit", |ch| ch.to_digit(10)).unwrap_err();
+        assert_eq!(error, ParseError::new(2, 3, "row of 2 cells"));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = test_grid();
+        let neighbors: Vec<Point> = grid.neighbors4(Point::new(0, 0)).collect();
+        assert_eq!(neighbors, vec![Point::new(0, 1), Point::new(1, 0)]);
+        assert_eq!(grid.neighbors8(Point::new(0, 1)).count(), 5);
+        assert_eq!(grid.neighbors8(Point::new(1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn test_views() {
+        let grid = test_grid();
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        let diagonals: Vec<String> = grid
+            .diagonals()
+            .map(|diagonal| diagonal.collect())
+            .collect();
+        assert_eq!(diagonals, vec!["d", "ae", "bf", "c"]);
+        let anti_diagonals: Vec<String> = grid
+            .anti_diagonals()
+            .map(|diagonal| diagonal.collect())
+            .collect();
+        assert_eq!(anti_diagonals, vec!["a", "db", "ec", "f"]);
+        let ray: String = grid
+            .ray(Point::new(0, 2), Direction::Left)
+            .map(|point| grid[point])
+            .collect();
+        assert_eq!(ray, "cba");
+    }
+
+    #[test]
+    fn test_transformations() {
+        let grid = test_grid();
+        assert_eq!(grid.transposed().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotated_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotated_counterclockwise().to_string(), "cf\nbe\nad\n");
+        assert_eq!(
+            grid.padded(1, '.').to_string(),
+            ".....\n.abc.\n.def.\n.....\n"
+        );
+    }
+}
@@ -0,0 +1,7 @@
+mod direction;
+mod grid;
+mod point;
+
+pub use direction::Direction;
+pub use grid::Grid;
+pub use point::Point;
@@ -0,0 +1,52 @@
+use crate::Direction;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub row: usize,
+    pub column: usize,
+}
+
+impl Point {
+    pub const fn new(row: usize, column: usize) -> Self {
+        Self { row, column }
+    }
+
+    /// Moves by the given deltas, `None` if the result would have a negative coordinate.
+    pub fn offset(&self, row_delta: isize, column_delta: isize) -> Option<Point> {
+        Some(Point {
+            row: self.row.checked_add_signed(row_delta)?,
+            column: self.column.checked_add_signed(column_delta)?,
+        })
+    }
+
+    /// Moves one step in the given direction, `None` if the result would have a negative coordinate.
+    pub fn step(&self, direction: Direction) -> Option<Point> {
+        let (row_delta, column_delta) = direction.delta();
+        self.offset(row_delta, column_delta)
+    }
+
+    pub fn manhattan_distance(&self, other: &Point) -> usize {
+        self.row.abs_diff(other.row) + self.column.abs_diff(other.column)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_step() {
+        let origin = Point::new(0, 0);
+        assert_eq!(origin.step(Direction::Up), None);
+        assert_eq!(origin.step(Direction::Left), None);
+        assert_eq!(origin.step(Direction::DownRight), Some(Point::new(1, 1)));
+    }
+
+    #[test]
+    fn test_offset() {
+        let point = Point::new(3, 4);
+        assert_eq!(point.offset(-3, 2), Some(Point::new(0, 6)));
+        assert_eq!(point.offset(-4, 0), None);
+        assert_eq!(point.manhattan_distance(&Point::new(1, 7)), 5);
+    }
+}
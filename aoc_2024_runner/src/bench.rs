@@ -0,0 +1,201 @@
+use std::{
+    fmt,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use aoc_2024_common::{default_parameters, ParseError, Part, Solution};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Step {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Step::Parse => write!(f, "parse"),
+            Step::Part1 => write!(f, "part1"),
+            Step::Part2 => write!(f, "part2"),
+        }
+    }
+}
+
+pub type Measurements = Vec<(Step, Vec<Duration>)>;
+
+/// Type-erased measurement entry point, see [`measure`].
+pub type Bencher = fn(&str, usize) -> Result<Measurements, ParseError>;
+
+/// Times `parse_input` and both parts with default parameters separately, `num_samples` times each.
+pub fn measure<S: Solution>(
+    input_text: &str,
+    num_samples: usize,
+) -> Result<Measurements, ParseError> {
+    let mut measurements: Measurements = [Step::Parse, Step::Part1, Step::Part2]
+        .into_iter()
+        .map(|step| (step, Vec::with_capacity(num_samples)))
+        .collect();
+    let parameters = default_parameters::<S>();
+
+    for _ in 0..num_samples {
+        let start = Instant::now();
+        let input = black_box(S::parse_input(black_box(input_text))?);
+        measurements[0].1.push(start.elapsed());
+
+        for (part, measurement) in Part::ALL.into_iter().zip(&mut measurements[1..]) {
+            let start = Instant::now();
+            let _ = black_box(S::process(black_box(&input), &parameters, part));
+            measurement.1.push(start.elapsed());
+        }
+    }
+
+    Ok(measurements)
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Statistics {
+    pub day: u8,
+    pub step: Step,
+    pub num_samples: usize,
+    pub mean_ns: f64,
+    pub median_ns: f64,
+    pub stddev_ns: f64,
+}
+
+impl Statistics {
+    pub fn from_samples(day: u8, step: Step, samples: &[Duration]) -> Self {
+        let mut samples_ns: Vec<f64> = samples
+            .iter()
+            .map(|sample| sample.as_nanos() as f64)
+            .collect();
+        samples_ns.sort_by(f64::total_cmp);
+
+        let num_samples = samples_ns.len();
+        let mean_ns = samples_ns.iter().sum::<f64>() / num_samples as f64;
+        let median_ns = if num_samples.is_multiple_of(2) {
+            (samples_ns[num_samples / 2 - 1] + samples_ns[num_samples / 2]) / 2.0
+        } else {
+            samples_ns[num_samples / 2]
+        };
+        let variance = samples_ns
+            .iter()
+            .map(|sample_ns| (sample_ns - mean_ns).powi(2))
+            .sum::<f64>()
+            / num_samples as f64;
+
+        Self {
+            day,
+            step,
+            num_samples,
+            mean_ns,
+            median_ns,
+            stddev_ns: variance.sqrt(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Report {
+    pub statistics: Vec<Statistics>,
+}
+
+impl Report {
+    pub fn load(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("report is serializable")
+    }
+
+    fn find(&self, day: u8, step: Step) -> Option<&Statistics> {
+        self.statistics
+            .iter()
+            .find(|statistics| (statistics.day == day) && (statistics.step == step))
+    }
+
+    /// Renders the statistics as a table, with the median change against `baseline` if given.
+    pub fn table(&self, baseline: Option<&Report>) -> String {
+        let mut table = format!(
+            "{:<4} {:<6} {:>11} {:>11} {:>11}",
+            "day", "step", "mean", "median", "stddev"
+        );
+        if baseline.is_some() {
+            table.push_str(&format!(" {:>11} {:>9}", "baseline", "change"));
+        }
+
+        for statistics in &self.statistics {
+            table.push_str(&format!(
+                "\n{:<4} {:<6} {:>11} {:>11} {:>11}",
+                format!("{:02}", statistics.day),
+                statistics.step,
+                format_nanos(statistics.mean_ns),
+                format_nanos(statistics.median_ns),
+                format_nanos(statistics.stddev_ns)
+            ));
+
+            if let Some(baseline) = baseline {
+                match baseline.find(statistics.day, statistics.step) {
+                    Some(baseline_statistics) => {
+                        let change = (statistics.median_ns - baseline_statistics.median_ns)
+                            / baseline_statistics.median_ns
+                            * 100.0;
+                        table.push_str(&format!(
+                            " {:>11} {:>+8.1}%",
+                            format_nanos(baseline_statistics.median_ns),
+                            change
+                        ));
+                    }
+                    None => table.push_str(&format!(" {:>11} {:>9}", "-", "-")),
+                }
+            }
+        }
+
+        table
+    }
+}
+
+fn format_nanos(nanos: f64) -> String {
+    if nanos < 1e3 {
+        format!("{nanos:.0}ns")
+    } else if nanos < 1e6 {
+        format!("{:.1}µs", nanos / 1e3)
+    } else if nanos < 1e9 {
+        format!("{:.1}ms", nanos / 1e6)
+    } else {
+        format!("{:.2}s", nanos / 1e9)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_statistics_from_samples() {
+        let samples = [4, 1, 3, 2].map(Duration::from_nanos);
+        let statistics = Statistics::from_samples(1, Step::Parse, &samples);
+        assert_eq!(statistics.num_samples, 4);
+        assert_eq!(statistics.mean_ns, 2.5);
+        assert_eq!(statistics.median_ns, 2.5);
+        assert_eq!(statistics.stddev_ns, 1.25f64.sqrt());
+    }
+
+    #[test]
+    fn test_report_round_trip() {
+        let report = Report {
+            statistics: vec![Statistics::from_samples(
+                7,
+                Step::Part2,
+                &[Duration::from_micros(3)],
+            )],
+        };
+        let loaded = Report::load(&report.to_json()).unwrap();
+        assert_eq!(loaded.statistics, report.statistics);
+        assert!(loaded.table(Some(&report)).contains("+0.0%"));
+    }
+}
@@ -0,0 +1,333 @@
+mod bench;
+mod solutions;
+mod verify;
+
+use std::{path::PathBuf, process::ExitCode};
+
+use aoc_2024_common::{OutputFormat, Part, PartResult};
+use bench::{Report, Statistics};
+use clap::{ArgGroup, Args, Parser, Subcommand};
+use solutions::{find_solution, RegisteredSolution, SOLUTIONS};
+use verify::{RecordedAnswers, Status, VerificationRow};
+
+#[derive(Debug, Parser)]
+#[command(name = "aoc", about = "Advent of Code 2024 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Solve one day or all days
+    Run(RunArgs),
+    /// Time parsing and both parts of one day or all days on their default input
+    Bench(BenchArgs),
+    /// Check the answers for the default inputs against the recorded ones
+    Verify(VerifyArgs),
+}
+
+#[derive(Debug, Args)]
+#[command(group(ArgGroup::new("selection").required(true).args(["day", "all"])))]
+struct RunArgs {
+    /// Day to solve
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+
+    /// Solve every registered day with its default input
+    #[arg(long)]
+    all: bool,
+
+    /// Part to solve, both parts if omitted
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Input file, defaults to aoc_2024_day_NN/input.txt
+    #[arg(long, requires = "day")]
+    input: Option<PathBuf>,
+
+    /// Output format of the results
+    #[arg(long, value_enum, default_value_t)]
+    format: OutputFormat,
+
+    /// Puzzle parameters of the day, e.g. `-- --num-rows 7 --num-columns 11` for day 14
+    #[arg(last = true, requires = "day")]
+    parameters: Vec<String>,
+}
+
+#[derive(Debug, Args)]
+struct BenchArgs {
+    /// Day to measure, all days if omitted
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+
+    /// Number of measurements per step
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+    samples: u64,
+
+    /// Write the results as JSON baseline
+    #[arg(long)]
+    save: Option<PathBuf>,
+
+    /// Compare the medians against a baseline written by --save
+    #[arg(long)]
+    baseline: Option<PathBuf>,
+}
+
+#[derive(Debug, Args)]
+struct VerifyArgs {
+    /// Day to check, all days if omitted
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+}
+
+fn default_input_file_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("aoc_2024_day_{day:02}/input.txt"))
+}
+
+fn run_solution(
+    solution: &RegisteredSolution,
+    input_file_path: &PathBuf,
+    parts: &[Part],
+    parameters: &[String],
+    format: OutputFormat,
+) -> bool {
+    match std::fs::read_to_string(input_file_path) {
+        Ok(input_text) => match (solution.solve)(&input_text, parts, parameters) {
+            Ok(results) => {
+                aoc_2024_common::print_results(solution.day, &input_text, &results, format);
+                true
+            }
+            Err(error) => {
+                eprintln!("{}", error.snippet(&input_text));
+                false
+            }
+        },
+        Err(error) => {
+            eprintln!("cannot read {}: {error}", input_file_path.display());
+            false
+        }
+    }
+}
+
+fn run(args: RunArgs) -> ExitCode {
+    let parts: Vec<Part> = match args.part {
+        Some(1) => vec![Part::One],
+        Some(2) => vec![Part::Two],
+        _ => Part::ALL.to_vec(),
+    };
+
+    let mut success = true;
+    if let Some(day) = args.day {
+        let Some(solution) = find_solution(day) else {
+            eprintln!("day {day} is not solved yet");
+            return ExitCode::FAILURE;
+        };
+        let input_file_path = args.input.unwrap_or(default_input_file_path(day));
+        success &= run_solution(
+            solution,
+            &input_file_path,
+            &parts,
+            &args.parameters,
+            args.format,
+        );
+    } else {
+        for solution in &SOLUTIONS {
+            if args.format == OutputFormat::Text {
+                println!("day {:02}", solution.day);
+            }
+            success &= run_solution(
+                solution,
+                &default_input_file_path(solution.day),
+                &parts,
+                &[],
+                args.format,
+            );
+        }
+    }
+
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn bench(args: BenchArgs) -> ExitCode {
+    if cfg!(debug_assertions) {
+        eprintln!("warning: measuring a debug build, use --release for meaningful numbers");
+    }
+
+    let baseline = match &args.baseline {
+        Some(baseline_file_path) => {
+            let baseline = std::fs::read_to_string(baseline_file_path)
+                .map_err(|error| error.to_string())
+                .and_then(|json| Report::load(&json).map_err(|error| error.to_string()));
+            match baseline {
+                Ok(baseline) => Some(baseline),
+                Err(error) => {
+                    eprintln!("cannot load {}: {error}", baseline_file_path.display());
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        None => None,
+    };
+
+    let solutions: Vec<&RegisteredSolution> = match args.day {
+        Some(day) => match find_solution(day) {
+            Some(solution) => vec![solution],
+            None => {
+                eprintln!("day {day} is not solved yet");
+                return ExitCode::FAILURE;
+            }
+        },
+        None => SOLUTIONS.iter().collect(),
+    };
+
+    let mut success = true;
+    let mut report = Report::default();
+    for solution in solutions {
+        let input_file_path = default_input_file_path(solution.day);
+        let input_text = match std::fs::read_to_string(&input_file_path) {
+            Ok(input_text) => input_text,
+            Err(error) => {
+                eprintln!("cannot read {}: {error}", input_file_path.display());
+                success = false;
+                continue;
+            }
+        };
+
+        match (solution.bench)(&input_text, args.samples as usize) {
+            Ok(measurements) => {
+                for (step, samples) in measurements {
+                    report
+                        .statistics
+                        .push(Statistics::from_samples(solution.day, step, &samples));
+                }
+            }
+            Err(error) => {
+                eprintln!("{}", error.snippet(&input_text));
+                success = false;
+            }
+        }
+    }
+
+    println!("{}", report.table(baseline.as_ref()));
+
+    if let Some(save_file_path) = &args.save {
+        if let Err(error) = std::fs::write(save_file_path, report.to_json()) {
+            eprintln!("cannot write {}: {error}", save_file_path.display());
+            success = false;
+        }
+    }
+
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn answers_file_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("aoc_2024_day_{day:02}/answers.toml"))
+}
+
+fn verify_solution(solution: &RegisteredSolution) -> Vec<VerificationRow> {
+    let rows = |expected: &str, actual: &str, status: Status| {
+        Part::ALL
+            .into_iter()
+            .map(|part| VerificationRow {
+                day: solution.day,
+                part,
+                expected: expected.to_string(),
+                actual: actual.to_string(),
+                status,
+            })
+            .collect()
+    };
+    let missing_rows = |actual: &str| rows("-", actual, Status::Missing);
+
+    let answers_file_path = answers_file_path(solution.day);
+    let recorded_answers = match std::fs::read_to_string(&answers_file_path) {
+        Ok(toml_text) => match RecordedAnswers::load(&toml_text) {
+            Ok(recorded_answers) => recorded_answers,
+            Err(error) => {
+                eprintln!("cannot load {}: {error}", answers_file_path.display());
+                return rows("invalid answers", "-", Status::Fail);
+            }
+        },
+        Err(_) => return missing_rows("-"),
+    };
+
+    let input_file_path = default_input_file_path(solution.day);
+    let Ok(input_text) = std::fs::read_to_string(&input_file_path) else {
+        return missing_rows("no input");
+    };
+
+    let results = match (solution.solve)(&input_text, &Part::ALL, &[]) {
+        Ok(results) => results,
+        Err(error) => {
+            eprintln!("{}", error.snippet(&input_text));
+            return Part::ALL
+                .into_iter()
+                .map(|part| VerificationRow {
+                    day: solution.day,
+                    part,
+                    expected: verify::format_recorded_answer(recorded_answers.get(part)),
+                    actual: "parse error".to_string(),
+                    status: Status::Fail,
+                })
+                .collect();
+        }
+    };
+
+    results
+        .into_iter()
+        .map(|PartResult { part, result, .. }| {
+            let recorded_answer = recorded_answers.get(part);
+            VerificationRow {
+                day: solution.day,
+                part,
+                expected: verify::format_recorded_answer(recorded_answer),
+                actual: match &result {
+                    Ok(answer) => answer.to_string(),
+                    Err(error) => error.to_string(),
+                },
+                status: verify::check(recorded_answer, &result),
+            }
+        })
+        .collect()
+}
+
+fn verify(args: VerifyArgs) -> ExitCode {
+    let solutions: Vec<&RegisteredSolution> = match args.day {
+        Some(day) => match find_solution(day) {
+            Some(solution) => vec![solution],
+            None => {
+                eprintln!("day {day} is not solved yet");
+                return ExitCode::FAILURE;
+            }
+        },
+        None => SOLUTIONS.iter().collect(),
+    };
+
+    let rows: Vec<VerificationRow> = solutions.into_iter().flat_map(verify_solution).collect();
+    println!("{}", verify::table(&rows));
+
+    if rows.iter().any(|row| row.status == Status::Fail) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run(args) => run(args),
+        Command::Bench(args) => bench(args),
+        Command::Verify(args) => verify(args),
+    }
+}
@@ -0,0 +1,45 @@
+use aoc_2024_common::{Solution, Solver};
+
+use crate::bench::{self, Bencher};
+
+pub struct RegisteredSolution {
+    pub day: u8,
+    pub solve: Solver,
+    pub bench: Bencher,
+}
+
+macro_rules! solution {
+    ($solution:ty) => {
+        RegisteredSolution {
+            day: <$solution>::DAY,
+            solve: aoc_2024_common::solve_with_arguments::<$solution>,
+            bench: bench::measure::<$solution>,
+        }
+    };
+}
+
+pub const SOLUTIONS: [RegisteredSolution; 19] = [
+    solution!(aoc_2024_day_01::Day01),
+    solution!(aoc_2024_day_02::Day02),
+    solution!(aoc_2024_day_03::Day03),
+    solution!(aoc_2024_day_04::Day04),
+    solution!(aoc_2024_day_05::Day05),
+    solution!(aoc_2024_day_06::Day06),
+    solution!(aoc_2024_day_07::Day07),
+    solution!(aoc_2024_day_08::Day08),
+    solution!(aoc_2024_day_09::Day09),
+    solution!(aoc_2024_day_10::Day10),
+    solution!(aoc_2024_day_11::Day11),
+    solution!(aoc_2024_day_12::Day12),
+    solution!(aoc_2024_day_13::Day13),
+    solution!(aoc_2024_day_14::Day14),
+    solution!(aoc_2024_day_15::Day15),
+    solution!(aoc_2024_day_16::Day16),
+    solution!(aoc_2024_day_17::Day17),
+    solution!(aoc_2024_day_18::Day18),
+    solution!(aoc_2024_day_19::Day19),
+];
+
+pub fn find_solution(day: u8) -> Option<&'static RegisteredSolution> {
+    SOLUTIONS.iter().find(|solution| solution.day == day)
+}
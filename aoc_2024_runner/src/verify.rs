@@ -0,0 +1,117 @@
+use std::fmt;
+
+use aoc_2024_common::{Answer, Part, SolveResult};
+use serde::Deserialize;
+
+/// Accepted answers for a day's `input.txt`, as stored in its `answers.toml`.
+#[derive(Debug, Default, Deserialize)]
+pub struct RecordedAnswers {
+    pub part1: Option<toml::Value>,
+    pub part2: Option<toml::Value>,
+}
+
+impl RecordedAnswers {
+    pub fn load(toml_text: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(toml_text)
+    }
+
+    pub fn get(&self, part: Part) -> Option<&toml::Value> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    Missing,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail => write!(f, "FAIL"),
+            Status::Missing => write!(f, "missing"),
+        }
+    }
+}
+
+fn is_same_answer(answer: &Answer, recorded_answer: &toml::Value) -> bool {
+    match (answer, recorded_answer) {
+        (Answer::Number(number), toml::Value::Integer(recorded_number)) => {
+            *number == *recorded_number as i128
+        }
+        (Answer::Text(text), toml::Value::String(recorded_text)) => text == recorded_text,
+        _ => false,
+    }
+}
+
+pub fn check(recorded_answer: Option<&toml::Value>, result: &SolveResult) -> Status {
+    match (recorded_answer, result) {
+        (None, _) => Status::Missing,
+        (Some(recorded_answer), Ok(answer)) if is_same_answer(answer, recorded_answer) => {
+            Status::Pass
+        }
+        _ => Status::Fail,
+    }
+}
+
+pub fn format_recorded_answer(recorded_answer: Option<&toml::Value>) -> String {
+    match recorded_answer {
+        Some(toml::Value::String(text)) => text.clone(),
+        Some(value) => value.to_string(),
+        None => "-".to_string(),
+    }
+}
+
+pub struct VerificationRow {
+    pub day: u8,
+    pub part: Part,
+    pub expected: String,
+    pub actual: String,
+    pub status: Status,
+}
+
+pub fn table(rows: &[VerificationRow]) -> String {
+    let mut table = format!(
+        "{:<4} {:<5} {:<20} {:<20} {}",
+        "day", "part", "expected", "actual", "status"
+    );
+    for row in rows {
+        table.push_str(&format!(
+            "\n{:<4} {:<5} {:<20} {:<20} {}",
+            format!("{:02}", row.day),
+            row.part.number(),
+            row.expected,
+            row.actual,
+            row.status
+        ));
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_2024_common::SolveError;
+
+    #[test]
+    fn test_check() {
+        let recorded_answers = RecordedAnswers::load("part1 = 292\npart2 = \"58,44\"\n").unwrap();
+        let part1 = recorded_answers.get(Part::One);
+        let part2 = recorded_answers.get(Part::Two);
+
+        assert_eq!(check(part1, &Ok(Answer::from(292u32))), Status::Pass);
+        assert_eq!(check(part1, &Ok(Answer::from(293u32))), Status::Fail);
+        assert_eq!(check(part1, &Ok(Answer::from("292"))), Status::Fail);
+        assert_eq!(check(part1, &Err(SolveError::NoAnswer)), Status::Fail);
+        assert_eq!(check(part2, &Ok(Answer::from("58,44"))), Status::Pass);
+        assert_eq!(check(None, &Ok(Answer::from(1u32))), Status::Missing);
+        assert_eq!(format_recorded_answer(part2), "58,44");
+    }
+}